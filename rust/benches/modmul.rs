@@ -0,0 +1,61 @@
+//! Compares the reduction backends of `ZMod` and `Zn` with the naive reduction via `%`.
+//!
+//! Run with `cargo bench --bench modmul`.
+
+use polydiv::types::*;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u64 = 10_000_000;
+const ODD: u64 = 0xffff_ffff_0000_0001;
+const EVEN: u64 = 0xffff_ffff_0000_0002;
+
+fn bench(name: &str, f: impl FnOnce() -> u64) {
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    println!(
+        "{name:<28} {:>8.2} ns/mul   (result {result})",
+        elapsed.as_nanos() as f64 / ITERATIONS as f64
+    );
+}
+
+fn naive(n: u64) -> u64 {
+    let n = black_box(n);
+    let mut x = black_box(3u64);
+    let y = black_box(0x1234_5678_9abc_def0 % n);
+    for _ in 0..ITERATIONS {
+        x = ((x as u128 * y as u128) % n as u128) as u64;
+    }
+    x
+}
+
+fn zmod<const N: u64>() -> u64 {
+    let mut x = black_box(ZMod::<N>::new(3));
+    let y = black_box(ZMod::<N>::new(0x1234_5678_9abc_def0));
+    for _ in 0..ITERATIONS {
+        x *= y;
+    }
+    x.value()
+}
+
+fn zn(n: u64) -> u64 {
+    let ring = Zn::new(black_box(n)).unwrap();
+    let mut x = ring.element(3);
+    let y = ring.element(0x1234_5678_9abc_def0);
+    for _ in 0..ITERATIONS {
+        x *= y;
+    }
+    x.value()
+}
+
+fn main() {
+    bench("naive % (odd modulus)", || naive(ODD));
+    bench("ZMod Montgomery", zmod::<ODD>);
+    bench("Zn Montgomery", || zn(ODD));
+    bench("naive % (even modulus)", || naive(EVEN));
+    bench("ZMod Barrett", zmod::<EVEN>);
+    bench("Zn Barrett", || zn(EVEN));
+    assert_eq!(naive(ODD), zmod::<ODD>());
+    assert_eq!(naive(EVEN), zn(EVEN));
+}
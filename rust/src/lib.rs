@@ -1,3 +1,4 @@
-#![feature(min_specialization)]
+pub mod number_theory;
 pub mod traits;
 pub mod types;
+pub mod util;
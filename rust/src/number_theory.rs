@@ -0,0 +1,10 @@
+mod arithmetic;
+mod discrete_log;
+mod factorization;
+mod primality;
+mod quadratic;
+pub use arithmetic::*;
+pub use discrete_log::*;
+pub use factorization::*;
+pub use primality::*;
+pub use quadratic::*;
@@ -0,0 +1,116 @@
+use super::{factor, Factorization};
+use crate::types::{Integer, Natural};
+use crate::util::gcd;
+
+/// [Arithmetic functions](https://en.wikipedia.org/wiki/Arithmetic_function), computed from the prime factorization.
+///
+/// As a factorization can describe numbers beyond the range of a `Natural`, these return `None` if the result overflows.
+impl Factorization<Natural> {
+    /// [Euler's totient function](https://en.wikipedia.org/wiki/Euler%27s_totient_function) *φ(n)*,
+    /// i.e. the number of integers in `1..=n` that are coprime to *n*.
+    /// This is also the order of the group of units of *ℤ/nℤ*.
+    pub fn euler_phi(&self) -> Option<Natural> {
+        self.factors()
+            .iter()
+            .try_fold(1u64, |acc, (p, e)| {
+                let p = p.value();
+                acc.checked_mul(p.checked_pow(e - 1)?)?.checked_mul(p - 1)
+            })
+            .map(Natural::new)
+    }
+
+    /// The [Möbius function](https://en.wikipedia.org/wiki/M%C3%B6bius_function) *μ(n)*.
+    ///
+    /// It is `0` if *n* is not square-free, and `(-1)^k` otherwise, where *k* is the number of prime factors.
+    pub fn mobius(&self) -> i8 {
+        if self.factors().iter().any(|(_, e)| *e > 1) {
+            0
+        } else if self.factors().len().is_multiple_of(2) {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Returns all positive divisors in ascending order.
+    pub fn divisors(&self) -> Option<Vec<Natural>> {
+        let mut divisors = vec![1u64];
+        for (p, e) in self.factors() {
+            let p = p.value();
+            let previous = divisors.len();
+            let mut power = 1u64;
+            for _ in 0..*e {
+                power = power.checked_mul(p)?;
+                for i in 0..previous {
+                    divisors.push(divisors[i].checked_mul(power)?);
+                }
+            }
+        }
+        divisors.sort_unstable();
+        Some(divisors.into_iter().map(Natural::new).collect())
+    }
+
+    /// The [divisor function](https://en.wikipedia.org/wiki/Divisor_function) *σₖ(n)*,
+    /// i.e. the sum of the *k*-th powers of all positive divisors.
+    ///
+    /// *σ₀(n)* is the number of divisors and *σ₁(n)* their sum.
+    pub fn sigma(&self, k: u32) -> Option<Natural> {
+        self.factors()
+            .iter()
+            .try_fold(1u64, |acc, (p, e)| {
+                let pk = p.value().checked_pow(k)?;
+                // 1 + p^k + p^2k + ... + p^ek
+                let mut sum = 1u64;
+                let mut power = 1u64;
+                for _ in 0..*e {
+                    power = power.checked_mul(pk)?;
+                    sum = sum.checked_add(power)?;
+                }
+                acc.checked_mul(sum)
+            })
+            .map(Natural::new)
+    }
+
+    /// The [Carmichael function](https://en.wikipedia.org/wiki/Carmichael_function) *λ(n)*,
+    /// i.e. the exponent of the group of units of *ℤ/nℤ*.
+    pub fn carmichael_lambda(&self) -> Option<Natural> {
+        self.factors()
+            .iter()
+            .try_fold(1u64, |acc, (p, e)| {
+                let lambda_pe = match (p.value(), *e) {
+                    (2, e) if e >= 3 => 1 << (e - 2),
+                    (p, e) => p.checked_pow(e - 1)?.checked_mul(p - 1)?,
+                };
+                let g = gcd(Integer::from(acc), Integer::from(lambda_pe)).value() as u64;
+                (acc / g).checked_mul(lambda_pe)
+            })
+            .map(Natural::new)
+    }
+}
+
+// For the factorization of a Natural n, φ(n), λ(n) and all divisors are at most n, so only σₖ can overflow.
+
+/// Euler's totient function *φ(n)*. See [`Factorization::euler_phi`].
+pub fn euler_phi(n: Natural) -> Natural {
+    factor(n).euler_phi().unwrap()
+}
+
+/// The Möbius function *μ(n)*. See [`Factorization::mobius`].
+pub fn mobius(n: Natural) -> i8 {
+    factor(n).mobius()
+}
+
+/// All positive divisors of *n* in ascending order. See [`Factorization::divisors`].
+pub fn divisors(n: Natural) -> Vec<Natural> {
+    factor(n).divisors().unwrap()
+}
+
+/// The divisor function *σₖ(n)*, or `None` if it doesn't fit into a Natural. See [`Factorization::sigma`].
+pub fn sigma_k(n: Natural, k: u32) -> Option<Natural> {
+    factor(n).sigma(k)
+}
+
+/// The Carmichael function *λ(n)*. See [`Factorization::carmichael_lambda`].
+pub fn carmichael_lambda(n: Natural) -> Natural {
+    factor(n).carmichael_lambda().unwrap()
+}
@@ -0,0 +1,227 @@
+use super::{factor, isqrt, Factorization};
+use crate::traits::{Group, SetSize};
+use crate::types::{Integer, Natural};
+use crate::util::{crt, extended_gcd, ExtendedGcd};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// Prime order subgroups up to this size are solved via baby-step giant-step, larger ones via Pollard's rho.
+const BSGS_LIMIT: u64 = 1 << 32;
+
+/// Computes the [discrete logarithm](https://en.wikipedia.org/wiki/Discrete_logarithm) of `target` to the `base`,
+/// i.e. the smallest `x >= 0` with `base^x = target`, in a finite group.
+///
+/// The order of the group is factored and the logarithm is computed via [`pohlig_hellman`].
+/// Returns `None` if `target` is not a power of `base`.
+///
+/// Panics if the group is not finite.
+pub fn discrete_log<G>(base: &G, target: &G) -> Option<Natural>
+where
+    G: Group + Clone + Eq + Hash,
+{
+    match base.size() {
+        SetSize::Finite(order) => pohlig_hellman(base, target, &factor(Natural::new(order))),
+        _ => panic!("Discrete logarithms can only be computed in finite groups"),
+    }
+}
+
+/// Computes the order of `element` from the factorization of a multiple of it (e.g. the group order).
+pub fn element_order<G>(element: &G, multiple: &Factorization<Natural>) -> Factorization<Natural>
+where
+    G: Group + Clone + Eq,
+{
+    let identity = element.identity();
+    let mut order = multiple.product().value();
+    let mut result = Factorization::new();
+    for (p, e) in multiple.factors() {
+        let p = p.value();
+        let mut exp = *e;
+        while exp > 0 && element.pow(order / p) == identity {
+            order /= p;
+            exp -= 1;
+        }
+        result.push(Natural::new(p), exp);
+    }
+    result
+}
+
+/// Computes the smallest `x < bound` with `base^x = target` with the
+/// [baby-step giant-step algorithm](https://en.wikipedia.org/wiki/Baby-step_giant-step).
+///
+/// This needs `O(√bound)` group operations and stores `O(√bound)` group elements.
+/// Usually `bound` is the order of `base` (or of the group).
+pub fn baby_step_giant_step<G>(base: &G, target: &G, bound: Natural) -> Option<Natural>
+where
+    G: Group + Clone + Eq + Hash,
+{
+    let bound = bound.value();
+    if bound == 0 {
+        return None;
+    }
+    let m = isqrt(bound - 1) + 1;
+
+    let mut baby_steps = HashMap::new();
+    let mut power = base.identity();
+    for j in 0..m {
+        baby_steps.entry(power.clone()).or_insert(j);
+        power.mut_op(base);
+    }
+
+    // power = base^m, so each giant step multiplies by base^(-m)
+    let giant_step = power.inverse();
+    let mut gamma = target.clone();
+    for i in 0..m {
+        if let Some(j) = baby_steps.get(&gamma) {
+            let x = i * m + j;
+            return (x < bound).then_some(Natural::new(x));
+        }
+        gamma.mut_op(&giant_step);
+    }
+    None
+}
+
+/// Computes `x` with `base^x = target` via [Pollard's rho algorithm for logarithms](https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm_for_logarithms),
+/// where `order` has to be the order of `base`.
+///
+/// It needs `O(√order)` group operations, but only constant memory. The walk through the group is pseudo-random,
+/// so a few different starting points are tried, before giving up with `None`.
+/// Works best if `order` is prime; otherwise use [`pohlig_hellman`].
+pub fn pollard_rho_log<G>(base: &G, target: &G, order: Natural) -> Option<Natural>
+where
+    G: Group + Clone + Eq + Hash,
+{
+    const MAX_ATTEMPTS: u32 = 32;
+    const MAX_CANDIDATES: u64 = 1 << 16;
+
+    let n = order.value();
+    if n <= 1 {
+        return (*target == base.identity()).then_some(Natural::new(0));
+    }
+    let add = |a: u64, b: u64| ((a as u128 + b as u128) % n as u128) as u64;
+
+    // Each element x = base^a * target^b is stored as (x, a, b)
+    let step = |(x, a, b): &(G, u64, u64)| match partition(x) {
+        0 => (x.op(base), add(*a, 1), *b),
+        1 => (x.op(x), add(*a, *a), add(*b, *b)),
+        _ => (x.op(target), *a, add(*b, 1)),
+    };
+
+    let mut seed = n;
+    for _ in 0..MAX_ATTEMPTS {
+        let (a, b) = (splitmix(&mut seed) % n, splitmix(&mut seed) % n);
+        let start = (base.pow(a).op(&target.pow(b)), a, b);
+
+        // Floyd's cycle detection
+        let mut tortoise = step(&start);
+        let mut hare = step(&tortoise);
+        while tortoise.0 != hare.0 {
+            tortoise = step(&tortoise);
+            hare = step(&step(&hare));
+        }
+
+        // base^a1 * target^b1 = base^a2 * target^b2, so (b1 - b2) * x = a2 - a1 (mod n)
+        let (_, a1, b1) = tortoise;
+        let (_, a2, b2) = hare;
+        let solutions = solve_linear_congruence(add(b1, n - b2), add(a2, n - a1), n);
+        if let Some((x, step, count)) = solutions {
+            if count <= MAX_CANDIDATES {
+                let found = (0..count)
+                    .map(|k| x + k * step)
+                    .find(|x| base.pow(*x) == *target);
+                if let Some(x) = found {
+                    return Some(Natural::new(x));
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Computes the smallest `x >= 0` with `base^x = target` with the
+/// [Pohlig-Hellman algorithm](https://en.wikipedia.org/wiki/Pohlig%E2%80%93Hellman_algorithm).
+///
+/// `order` is the factorization of a multiple of the order of `base`, e.g. of the group order.
+/// The logarithm is computed separately in each subgroup of prime power order, digit by digit,
+/// which reduces the work to logarithms in subgroups of prime order. Those are solved via [`baby_step_giant_step`]
+/// for small primes and [`pollard_rho_log`] for large ones. The partial results are combined via the
+/// Chinese Remainder Theorem.
+///
+/// Returns `None` if `target` is not a power of `base`.
+pub fn pohlig_hellman<G>(base: &G, target: &G, order: &Factorization<Natural>) -> Option<Natural>
+where
+    G: Group + Clone + Eq + Hash,
+{
+    let order = element_order(base, order);
+    let n = order.product().value();
+    if target.pow(n) != target.identity() {
+        // The order of target doesn't divide the order of base, so it can't be a power of base
+        return None;
+    }
+
+    let mut congruences = Vec::new();
+    for (p, e) in order.factors() {
+        let (p, e) = (p.value(), *e);
+        let pe = p.pow(e);
+        // g has order p^e and gamma has order p
+        let g = base.pow(n / pe);
+        let h = target.pow(n / pe);
+        let gamma = g.pow(pe / p);
+        let g_inv = g.inverse();
+
+        // x = d_0 + d_1 * p + ... + d_(e-1) * p^(e-1), where gamma^(d_k) = (g^(-x) * h)^(p^(e-1-k))
+        let mut x = 0;
+        let mut pk = 1;
+        for _ in 0..e {
+            let hk = g_inv.pow(x).op(&h).pow(pe / pk / p);
+            let d = prime_order_log(&gamma, &hk, p)?;
+            x += d * pk;
+            pk *= p;
+        }
+        congruences.push((Integer::from(x), Integer::from(pe)));
+    }
+
+    let (x, _) = crt(&congruences).expect("The prime power orders are coprime");
+    let x = x.value() as u64;
+    (base.pow(x) == *target).then_some(Natural::new(x))
+}
+
+fn prime_order_log<G>(base: &G, target: &G, p: u64) -> Option<u64>
+where
+    G: Group + Clone + Eq + Hash,
+{
+    let x = if p < BSGS_LIMIT {
+        baby_step_giant_step(base, target, Natural::new(p))
+    } else {
+        pollard_rho_log(base, target, Natural::new(p))
+    };
+    x.map(|x| x.value())
+}
+
+/// Solves `c * x = r (mod n)`. Returns the smallest solution `x`, the distance between two solutions and the number of solutions.
+fn solve_linear_congruence(c: u64, r: u64, n: u64) -> Option<(u64, u64, u64)> {
+    let ExtendedGcd { gcd, x, .. } = extended_gcd(Integer::from(c), Integer::from(n));
+    let d = gcd.value() as u64;
+    if !r.is_multiple_of(d) {
+        return None;
+    }
+    let step = n / d;
+    let x = (x.value().rem_euclid(step as i128) as u128 * (r / d) as u128 % step as u128) as u64;
+    Some((x, step, d))
+}
+
+/// Splits the group into three parts of roughly the same size for the pseudo-random walk of Pollard's rho.
+fn partition<G: Hash>(x: &G) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish() % 3
+}
+
+/// [SplitMix64](https://prng.di.unimi.it/splitmix64.c), used to pick the starting points of Pollard's rho.
+fn splitmix(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e3779b97f4a7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
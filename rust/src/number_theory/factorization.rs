@@ -0,0 +1,242 @@
+use super::is_prime_u64;
+use crate::types::{Integer, Natural, Zn};
+use crate::util::gcd;
+use std::fmt::Display;
+use std::ops::Mul;
+
+/// The factorization of an element into powers of (pairwise non-associated) primes and a unit.
+///
+/// The factors are stored as pairs `(p, e)` representing `p^e`. If the unit is `None`, it is the trivial unit `1`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Factorization<T> {
+    unit: Option<T>,
+    factors: Vec<(T, u32)>,
+}
+
+impl<T> Factorization<T> {
+    pub fn new() -> Self {
+        Factorization {
+            unit: None,
+            factors: Vec::new(),
+        }
+    }
+
+    pub fn with_unit(unit: T) -> Self {
+        Factorization {
+            unit: Some(unit),
+            factors: Vec::new(),
+        }
+    }
+
+    pub fn unit(&self) -> Option<&T> {
+        self.unit.as_ref()
+    }
+
+    pub fn set_unit(&mut self, unit: Option<T>) {
+        self.unit = unit;
+    }
+
+    pub fn factors(&self) -> &Vec<(T, u32)> {
+        &self.factors
+    }
+
+    /// Iterates over the distinct prime factors.
+    pub fn primes(&self) -> impl Iterator<Item = &T> {
+        self.factors.iter().map(|(p, _)| p)
+    }
+
+    /// Returns the number of prime factors counted with multiplicity.
+    pub fn count(&self) -> u32 {
+        self.factors.iter().map(|(_, e)| e).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.factors.is_empty()
+    }
+
+    /// Multiplies the factorization with `prime^exp`.
+    pub fn push(&mut self, prime: T, exp: u32)
+    where
+        T: PartialEq,
+    {
+        if exp == 0 {
+            return;
+        }
+        match self.factors.iter_mut().find(|(p, _)| *p == prime) {
+            Some((_, e)) => *e += exp,
+            None => self.factors.push((prime, exp)),
+        }
+    }
+
+    /// Sorts the factors by their primes.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.factors.sort_by(|(p, _), (q, _)| p.cmp(q));
+    }
+
+    /// Sorts the factors by a key of their primes, e.g. for primes without a natural order.
+    pub fn sort_by_key<K: Ord>(&mut self, mut key: impl FnMut(&T) -> K) {
+        self.factors.sort_by_key(|(p, _)| key(p));
+    }
+
+    /// Multiplies all factors (and the unit) back together.
+    pub fn product(&self) -> T
+    where
+        T: Clone + Mul<Output = T> + From<u64>,
+    {
+        let init = self.unit.clone().unwrap_or_else(|| T::from(1));
+        self.factors.iter().fold(init, |acc, (p, e)| {
+            (0..*e).fold(acc, |acc, _| acc * p.clone())
+        })
+    }
+}
+
+impl<T> Default for Factorization<T> {
+    fn default() -> Self {
+        Factorization::new()
+    }
+}
+
+impl<T> IntoIterator for Factorization<T> {
+    type Item = (T, u32);
+    type IntoIter = std::vec::IntoIter<(T, u32)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.factors.into_iter()
+    }
+}
+
+impl<T: Display> Display for Factorization<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts: Vec<String> = Vec::new();
+        if let Some(unit) = &self.unit {
+            parts.push(format!("{unit}"));
+        }
+        for (p, e) in &self.factors {
+            if *e == 1 {
+                parts.push(format!("{p}"));
+            } else {
+                parts.push(format!("{p}^{e}"));
+            }
+        }
+        if parts.is_empty() {
+            write!(f, "1")
+        } else {
+            write!(f, "{}", parts.join(" * "))
+        }
+    }
+}
+
+/// Factors a Natural into its prime factors, which are sorted in ascending order.
+///
+/// Small factors are found via trial division, the remaining ones via
+/// Pollard's rho algorithm (see [`pollard_rho_brent`]).
+///
+/// Panics if `n` is zero.
+pub fn factor(n: Natural) -> Factorization<Natural> {
+    const TRIAL_DIVISION_BOUND: u64 = 1 << 10;
+
+    let mut n = n.value();
+    assert!(n != 0, "Cannot factor zero, as every prime divides it");
+    let mut factorization = Factorization::new();
+
+    let twos = n.trailing_zeros();
+    factorization.push(Natural::new(2), twos);
+    n >>= twos;
+    let mut p = 3;
+    while p < TRIAL_DIVISION_BOUND && p * p <= n {
+        let mut exp = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            exp += 1;
+        }
+        factorization.push(Natural::new(p), exp);
+        p += 2;
+    }
+
+    if n > 1 {
+        let mut stack = vec![n];
+        while let Some(m) = stack.pop() {
+            if is_prime_u64(m) {
+                factorization.push(Natural::new(m), 1);
+            } else {
+                let d = pollard_rho_brent(Natural::new(m))
+                    .expect("Pollard's rho algorithm failed to find a factor")
+                    .value();
+                stack.push(d);
+                stack.push(m / d);
+            }
+        }
+    }
+
+    factorization.sort();
+    factorization
+}
+
+/// Tries to find a non-trivial factor of a composite `n` with
+/// [Pollard's rho algorithm](https://en.wikipedia.org/wiki/Pollard%27s_rho_algorithm) in Brent's variant.
+///
+/// Returns `None` if `n` is prime, `n < 4` or no factor was found for any of the tried polynomials `x^2 + c`.
+pub fn pollard_rho_brent(n: Natural) -> Option<Natural> {
+    const BATCH_SIZE: u64 = 128;
+    const MAX_POLYNOMIALS: u64 = 32;
+
+    let n = n.value();
+    if n < 4 || is_prime_u64(n) {
+        return None;
+    }
+    if n.is_multiple_of(2) {
+        return Some(Natural::new(2));
+    }
+    let ring = Zn::new(n).unwrap();
+
+    for c in 1..=MAX_POLYNOMIALS {
+        let c = ring.element(c);
+        let f = |x| x * x + c;
+        let mut y = ring.element(2);
+        let mut x = y;
+        let mut ys = y;
+        let mut g = 1;
+        let mut q = ring.one();
+        let mut r = 1;
+
+        while g == 1 {
+            x = y;
+            for _ in 0..r {
+                y = f(y);
+            }
+            let mut k = 0;
+            while k < r && g == 1 {
+                ys = y;
+                // Multiply the differences together, so that only one gcd per batch is needed
+                for _ in 0..BATCH_SIZE.min(r - k) {
+                    y = f(y);
+                    q *= x - y;
+                }
+                g = gcd_u64(q.value(), n);
+                k += BATCH_SIZE;
+            }
+            r *= 2;
+        }
+
+        if g == n {
+            // The batch overshot, so backtrack one step at a time
+            loop {
+                ys = f(ys);
+                g = gcd_u64((x - ys).value(), n);
+                if g > 1 {
+                    break;
+                }
+            }
+        }
+        if g != n {
+            return Some(Natural::new(g));
+        }
+    }
+    None
+}
+
+fn gcd_u64(a: u64, b: u64) -> u64 {
+    gcd(Integer::from(a), Integer::from(b)).value() as u64
+}
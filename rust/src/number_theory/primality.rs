@@ -0,0 +1,187 @@
+use super::jacobi_symbol;
+use crate::types::{Integer, Natural, Zn, ZnElement};
+
+/// Deterministic [Miller-Rabin test](https://en.wikipedia.org/wiki/Miller%E2%80%93Rabin_primality_test) for 64-bit integers.
+///
+/// The bases used are sufficient to make the test deterministic for all `n < 2^64`.
+/// This function is `const`, so that the modulus of [`ZMod`](crate::types::ZMod) can be checked at compile time before using field operations.
+pub const fn is_prime_u64(n: u64) -> bool {
+    const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+        ((a as u128 * b as u128) % n as u128) as u64
+    }
+
+    const fn pow_mod(base: u64, exp: u64, n: u64) -> u64 {
+        let mut base = base % n;
+        let mut exp = exp;
+        let mut result = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result = mul_mod(result, base, n);
+            }
+            base = mul_mod(base, base, n);
+            exp >>= 1;
+        }
+        result
+    }
+
+    // These bases are sufficient for all n < 2^64
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    let mut i = 0;
+    while i < BASES.len() {
+        if n.is_multiple_of(BASES[i]) {
+            return n == BASES[i];
+        }
+        i += 1;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    let mut i = 0;
+    'bases: while i < BASES.len() {
+        let mut x = pow_mod(BASES[i], d, n);
+        i += 1;
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        let mut r = 1;
+        while r < s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'bases;
+            }
+            r += 1;
+        }
+        return false;
+    }
+    true
+}
+
+/// Checks whether `n` is a [strong probable prime](https://en.wikipedia.org/wiki/Strong_pseudoprime) to the given base,
+/// i.e. whether `n` passes a single round of the Miller-Rabin test.
+///
+/// Bases divisible by `n` are no witnesses at all, so the test fails for them.
+pub fn is_strong_probable_prime(n: Natural, base: Natural) -> bool {
+    let n = n.value();
+    if n < 2 || n.is_multiple_of(2) {
+        return n == 2;
+    }
+    let ring = Zn::new(n).unwrap();
+    let s = (n - 1).trailing_zeros();
+    let mut x = ring.element(base.value()).pow((n - 1) >> s);
+    if x.is_zero() {
+        return false;
+    }
+    if x.is_one() || x == -ring.one() {
+        return true;
+    }
+    for _ in 1..s {
+        x *= x;
+        if x == -ring.one() {
+            return true;
+        }
+    }
+    false
+}
+
+/// Checks whether `n` is a [strong Lucas probable prime](https://en.wikipedia.org/wiki/Lucas_pseudoprime#Strong_Lucas_pseudoprimes),
+/// where the parameters of the Lucas sequence are chosen with Selfridge's method.
+///
+/// Together with [`is_strong_probable_prime`] to base 2 this forms the Baillie-PSW test (see [`is_prime_bpsw`]).
+pub fn is_strong_lucas_probable_prime(n: Natural) -> bool {
+    let n = n.value();
+    if n < 2 || n.is_multiple_of(2) {
+        return n == 2;
+    }
+    if is_square(n) {
+        return false;
+    }
+
+    // Find the first D in 5, -7, 9, -11, ... with (D/n) = -1
+    let mut d: i128 = 5;
+    loop {
+        match jacobi_symbol(Integer::new(d), Natural::new(n)) {
+            -1 => break,
+            0 if d.unsigned_abs() != n as u128 => return false,
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+
+    let ring = Zn::new(n).unwrap();
+    let int = |x: i128| ring.element(x.rem_euclid(n as i128) as u64);
+    let p = ring.one();
+    let q = int((1 - d) / 4);
+    let d = int(d);
+    let half = |x: ZnElement| x * int((n as i128 + 1) / 2);
+
+    let s = (n + 1).trailing_zeros();
+    let k = (n + 1) >> s;
+    // Compute U_k, V_k and Q^k from left to right via the doubling formulas
+    let (mut u, mut v, mut qk) = (ring.one(), p, q);
+    for bit in (0..(63 - k.leading_zeros())).rev() {
+        (u, v, qk) = (u * v, v * v - qk - qk, qk * qk);
+        if (k >> bit) & 1 == 1 {
+            (u, v, qk) = (half(p * u + v), half(d * u + p * v), qk * q);
+        }
+    }
+
+    if u.is_zero() {
+        return true;
+    }
+    for _ in 0..s {
+        if v.is_zero() {
+            return true;
+        }
+        (v, qk) = (v * v - qk - qk, qk * qk);
+    }
+    false
+}
+
+/// The [Baillie-PSW primality test](https://en.wikipedia.org/wiki/Baillie%E2%80%93PSW_primality_test).
+///
+/// Combines a Miller-Rabin test to base 2 with a strong Lucas test.
+/// There is no known composite number that passes this test and it is proven to be correct for all `n < 2^64`,
+/// so for Naturals it agrees with the deterministic [`Natural::is_prime`](crate::types::Natural::is_prime).
+pub fn is_prime_bpsw(n: Natural) -> bool {
+    const SMALL_PRIMES: [u64; 11] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+    let value = n.value();
+    if value < 2 {
+        return false;
+    }
+    for p in SMALL_PRIMES {
+        if value.is_multiple_of(p) {
+            return value == p;
+        }
+    }
+    is_strong_probable_prime(n, Natural::new(2)) && is_strong_lucas_probable_prime(n)
+}
+
+/// Iterates over all primes below `bound` in descending order.
+///
+/// This is a convenient source of moduli for multi-modular algorithms (see [`crate::util::lift_rationals`]).
+pub fn primes_below(bound: Natural) -> impl Iterator<Item = Natural> {
+    (2..bound.value())
+        .rev()
+        .filter(|n| is_prime_u64(*n))
+        .map(Natural::new)
+}
+
+/// Returns `⌊√n⌋`.
+pub(crate) fn isqrt(n: u64) -> u64 {
+    let mut x = (n as f64).sqrt() as u64;
+    while x as u128 * x as u128 > n as u128 {
+        x -= 1;
+    }
+    while (x + 1) as u128 * (x + 1) as u128 <= n as u128 {
+        x += 1;
+    }
+    x
+}
+
+fn is_square(n: u64) -> bool {
+    let r = isqrt(n);
+    r * r == n
+}
@@ -0,0 +1,200 @@
+use super::is_prime_u64;
+use crate::types::{Integer, Natural, Zn, ZnElement};
+
+/// The [Jacobi symbol](https://en.wikipedia.org/wiki/Jacobi_symbol) `(a/n)` for odd `n`.
+///
+/// Panics if `n` is even.
+pub fn jacobi_symbol(a: Integer, n: Natural) -> i8 {
+    let mut n = n.value();
+    if n.is_multiple_of(2) {
+        panic!("The Jacobi symbol is only defined for odd n")
+    }
+    let mut a = a.value().rem_euclid(n as i128) as u64;
+    let mut result = 1;
+    while a != 0 {
+        while a.is_multiple_of(2) {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        (a, n) = (n, a);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// The [Legendre symbol](https://en.wikipedia.org/wiki/Legendre_symbol) `(a/p)` for an odd prime `p`.
+///
+/// It is `1` if `a` is a non-zero square modulo `p`, `-1` if it is not a square and `0` if `p` divides `a`.
+/// For primes this coincides with the Jacobi symbol, whose computation doesn't need any exponentiation.
+pub fn legendre_symbol(a: Integer, p: Natural) -> i8 {
+    jacobi_symbol(a, p)
+}
+
+/// Computes a square root of `a` modulo the prime `p`, if one exists.
+///
+/// Of the two roots `r` and `p - r`, the smaller one is returned.
+/// The root is computed with the [Tonelli-Shanks algorithm](https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm),
+/// unless `p - 1` is divisible by a large power of two, where
+/// [Cipolla's algorithm](https://en.wikipedia.org/wiki/Cipolla%27s_algorithm) is faster.
+///
+/// Panics if `p` is not prime.
+pub fn sqrt_mod_prime(a: Natural, p: Natural) -> Option<Natural> {
+    assert!(is_prime_u64(p.value()), "The modulus has to be a prime");
+    sqrt_mod_known_prime(a, p)
+}
+
+/// [`sqrt_mod_prime`] without the primality test, for callers that already know `p` to be prime.
+pub(crate) fn sqrt_mod_known_prime(a: Natural, p: Natural) -> Option<Natural> {
+    let ring = Zn::new(p.value()).expect("The modulus has to be a prime");
+    let a = ring.element(a.value());
+    let root = if p.value() == 2 || a.is_zero() {
+        a
+    } else if jacobi_symbol(a.into(), p) != 1 {
+        return None;
+    } else if (p.value() - 1).trailing_zeros() <= 16 {
+        tonelli_shanks(a)
+    } else {
+        cipolla(a)
+    };
+    Some(Natural::new(root.value().min((-root).value())))
+}
+
+/// Computes a square root of `a` modulo `p^k` for a prime `p`, if one exists.
+///
+/// A root modulo `p` is lifted to a root modulo `p^k` via [Hensel's lemma](https://en.wikipedia.org/wiki/Hensel%27s_lemma).
+/// If `a` is divisible by `p`, the even power of `p` is split off first.
+///
+/// Panics if `p^k` doesn't fit into 64 bits.
+pub fn sqrt_mod_prime_power(a: Integer, p: Natural, k: u32) -> Option<Natural> {
+    let p = p.value();
+    let modulus = p.checked_pow(k).expect("p^k has to fit into 64 bits");
+    if k == 0 {
+        return Some(Natural::new(0));
+    }
+    let mut a = a.value().rem_euclid(modulus as i128) as u64;
+    if a == 0 {
+        return Some(Natural::new(0));
+    }
+
+    // a = p^(2j) * b with p not dividing b, so x = p^j * y with y^2 = b mod p^(k - 2j)
+    let mut j = 0;
+    while a.is_multiple_of(p) {
+        if !a.is_multiple_of(p * p) {
+            return None;
+        }
+        a /= p * p;
+        j += 1;
+    }
+    let y = sqrt_of_unit_mod_prime_power(a, p, k - 2 * j)?;
+    let x = (y as u128 * p.pow(j) as u128 % modulus as u128) as u64;
+    Some(Natural::new(x.min(modulus - x) % modulus))
+}
+
+/// Square root of `a` modulo `p^k`, where `p` doesn't divide `a`.
+fn sqrt_of_unit_mod_prime_power(a: u64, p: u64, k: u32) -> Option<u64> {
+    let modulus = p.pow(k);
+    if modulus == 1 {
+        return Some(0);
+    }
+    let ring = Zn::new(modulus).unwrap();
+    let a = ring.element(a);
+
+    if p == 2 {
+        // Odd squares are 1 modulo 8, and the root can be lifted bit by bit
+        let required = (1u64 << k.min(3)) - 1;
+        if a.value() & required != 1 & required {
+            return None;
+        }
+        let mut root = ring.one();
+        for i in 3..k {
+            // root^2 = a mod 2^i, so either root or root + 2^(i-1) is a root modulo 2^(i+1)
+            if ((root * root).value() ^ a.value()) & (1 << i) != 0 {
+                root += ring.element(1 << (i - 1));
+            }
+        }
+        return Some(root.value());
+    }
+
+    let root = sqrt_mod_prime(Natural::new(a.value() % p), Natural::new(p))?;
+    let mut root = ring.element(root.value());
+    // Newton's iteration x ↦ x - (x^2 - a) / 2x doubles the precision in each step
+    let mut precision = 1;
+    while precision < k {
+        root -= (root * root - a)
+            * (root + root)
+                .inverse()
+                .expect("2x is a unit modulo p^k for odd p");
+        precision *= 2;
+    }
+    Some(root.value())
+}
+
+fn tonelli_shanks(a: ZnElement) -> ZnElement {
+    let ring = *a.ring();
+    let p = ring.modulus();
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+
+    // Any non-residue generates the 2-Sylow subgroup when raised to the q-th power
+    let z = (2..p)
+        .map(|z| ring.element(z))
+        .find(|z| jacobi_symbol((*z).into(), Natural::new(p)) == -1)
+        .expect("Every odd prime has a quadratic non-residue");
+    let mut m = s;
+    let mut c = z.pow(q);
+    let mut t = a.pow(q);
+    let mut r = a.pow(q.div_ceil(2));
+
+    while !t.is_one() {
+        // Find the smallest i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t2 = t;
+        while !t2.is_one() {
+            t2 *= t2;
+            i += 1;
+        }
+        let b = c.pow(1 << (m - i - 1));
+        m = i;
+        c = b * b;
+        t *= c;
+        r *= b;
+    }
+    r
+}
+
+fn cipolla(a: ZnElement) -> ZnElement {
+    let ring = *a.ring();
+    let p = ring.modulus();
+
+    // Find t, such that t^2 - a is a non-residue, and compute in F_p(ω) with ω^2 = t^2 - a
+    let (t, w2) = (1..p)
+        .map(|t| ring.element(t))
+        .map(|t| (t, t * t - a))
+        .find(|(_, w2)| jacobi_symbol((*w2).into(), Natural::new(p)) == -1)
+        .expect("Half of all t yield a non-residue");
+    let mul = |(x1, y1): (ZnElement, ZnElement), (x2, y2): (ZnElement, ZnElement)| {
+        (x1 * x2 + y1 * y2 * w2, x1 * y2 + y1 * x2)
+    };
+
+    // (t + ω)^((p + 1) / 2)
+    let mut base = (t, ring.one());
+    let mut result = (ring.one(), ring.zero());
+    let mut exp = p.div_ceil(2);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        exp >>= 1;
+    }
+    result.0
+}
@@ -1,4 +1,5 @@
 // //!
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
 // TODO: Split this file into seperate modules & files
 // TODO: Think of a useful API-Structure (e.g. having modules for finite and infinite structures)
@@ -72,141 +73,219 @@ pub trait EnumerableSet<T>: IntoIterator<Item = T> {
 }
 impl<T, S: EnumerableSet<T>> Set<T> for S {
     fn size(&self) -> SetSize {
-        <Self as EnumerableSet<T>>::size(&self)
+        <Self as EnumerableSet<T>>::size(self)
     }
     fn contains(&self, item: &T) -> bool {
-        <Self as EnumerableSet<T>>::contains(&self, item)
+        <Self as EnumerableSet<T>>::contains(self, item)
     }
 }
 
+/// Marker for structures, whose (multiplicative) operation is [commutative](https://en.wikipedia.org/wiki/Commutative_property).
+///
+/// For the ring traits below this always refers to the multiplication,
+/// as the addition of a ring is commutative by definition.
 pub trait Commutative {}
+/// Marker for structures, whose (multiplicative) operation is [associative](https://en.wikipedia.org/wiki/Associative_property).
 pub trait Associative {}
 pub trait LeftDistributive {}
 pub trait RightDistributive {}
-impl<T: LeftDistributive + RightDistributive> Distributive for T {}
+/// Marker for structures, whose multiplication [distributes](https://en.wikipedia.org/wiki/Distributive_property) over the addition from both sides.
+///
+/// This trait is implemented automatically for all types that are both `LeftDistributive` and `RightDistributive`.
 pub trait Distributive {}
-impl<T: Distributive> LeftDistributive for T {}
-impl<T: Distributive> RightDistributive for T {}
+impl<T: LeftDistributive + RightDistributive> Distributive for T {}
+/// Marker for [formally real](https://en.wikipedia.org/wiki/Formally_real_field) fields,
+/// in which a sum of squares is only zero if all summands are zero (e.g. the Rationals, but no finite field).
+/// Equivalently, `-1` is not a sum of squares.
+pub trait FormallyReal {}
 
+/// Rings, for which it is known at compile time whether `-1` is a square.
 ///
+/// For a field `F` this decides whether *x² + 1* is irreducible, i.e. whether adjoining a square root of `-1`
+/// (see [`Complex`](crate::types::Complex)) yields a field again.
+pub trait MinusOneSquare {
+    const IS_SQUARE: bool;
+}
+
+/// A set together with a single binary operation.
+///
+/// Like the ring traits below, this is implemented on the element types. The set an element belongs to
+/// is returned by `domain`, which takes the element itself, so that structures only known at runtime
+/// (e.g. the units modulo a runtime modulus) can provide it as well.
 pub trait AlgebraicStructure: Sized {
     type Domain: Set<Self>;
     type Output;
-    fn domain() -> Self::Domain;
+    fn domain(&self) -> Self::Domain;
     fn contains(&self) -> bool {
-        Self::domain().contains(self)
+        self.domain().contains(self)
     }
     fn size(&self) -> SetSize {
-        Self::domain().size()
+        self.domain().size()
     }
     fn op(&self, rhs: &Self) -> Self::Output;
     fn mut_op(&mut self, rhs: &Self);
 }
 
 pub trait Magma: AlgebraicStructure<Output = Self> {}
-///
 pub trait SemiGroup: Magma + Associative {}
 
-///
 pub trait Monoid: SemiGroup {
     fn identity(&self) -> Self;
+
+    /// Applies the operation `exp` times to this element, i.e. computes `self^exp` in multiplicative notation.
+    fn pow(&self, exp: u64) -> Self
+    where
+        Self: Clone,
+    {
+        let mut base = self.clone();
+        let mut exp = exp;
+        let mut result = self.identity();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result.mut_op(&base);
+            }
+            base = base.op(&base);
+            exp >>= 1;
+        }
+        result
+    }
 }
-///
+
+/// A [Group](https://en.wikipedia.org/wiki/Group_(mathematics)) is a monoid, in which every element has an inverse.
 pub trait Group: Monoid {
-    fn inverse(&self, item: &Self) -> Self;
+    fn inverse(&self) -> Self;
 }
 
-pub trait NearRing: Sized
-where
-    <Self as NearRing>::AdditiveGroup: 'static,
-    Self: Into<&'static <Self as NearRing>::AdditiveGroup>,
-    Self: From<<Self as NearRing>::AdditiveGroup>,
+/// A [Near-Ring](https://en.wikipedia.org/wiki/Near-ring) is a set with two operations, addition and multiplication,
+/// where the addition forms a group and the multiplication a semigroup.
+///
+/// Unlike the single-operation structures above, the ring-like traits are implemented directly on the element types
+/// and use Rust's operator traits for their operations, so that generic algorithms can simply write `a + b * c`.
+/// Only the right distributive law is expected to hold for near-rings; types should mark which laws hold
+/// via the `LeftDistributive` and `RightDistributive` marker traits.
+pub trait NearRing:
+    Sized
+    + Clone
+    + PartialEq
+    + Associative
+    + Add<Output = Self>
+    + AddAssign
+    + Sub<Output = Self>
+    + SubAssign
+    + Neg<Output = Self>
+    + Mul<Output = Self>
+    + MulAssign
 {
-    type AdditiveGroup: Group<Domain = Self>;
-    type MultiplicativeGroup: SemiGroup<Domain = Self>;
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Checks whether this element is the additive identity.
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+}
 
-    fn additive_group(&self) -> Self::AdditiveGroup;
+/// A [Rng](https://en.wikipedia.org/wiki/Rng_(algebra)) is a ring without the requirement of a multiplicative identity.
+///
+/// The addition has to be commutative and the multiplication has to distribute over the addition from both sides.
+pub trait Rng: NearRing + Distributive {}
+impl<T: NearRing + Distributive> Rng for T {}
 
-    fn multiplicative_group(&self) -> Self::MultiplicativeGroup;
+/// A [Ring](https://en.wikipedia.org/wiki/Ring_(mathematics)) is a Rng with a multiplicative identity.
+pub trait Ring: Rng {
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
 
-    fn additive_identity(&self) -> Self {
-        self.additive_group().identity().into()
+    /// Checks whether this element is the multiplicative identity.
+    fn is_one(&self) -> bool {
+        *self == Self::one()
     }
 
-    fn additive_inverse(&self, item: &Self) -> Self {
-        self.additive_group().inverse(item).into()
+    /// Returns the multiplicative inverse of this element, if it is a [unit](https://en.wikipedia.org/wiki/Unit_(ring_theory)).
+    fn inverse(&self) -> Option<Self>;
+
+    /// Checks whether this element is a unit, i.e. whether it has a multiplicative inverse.
+    fn is_unit(&self) -> bool {
+        self.inverse().is_some()
     }
 
-    fn add(&self, lhs: &Self, rhs: &Self) -> Self {
-        Group::op(self.additive_group(), lhs, rhs)
+    /// Computes the [convolution](https://en.wikipedia.org/wiki/Convolution#Discrete_convolution) `cₖ = Σ aᵢbₖ₋ᵢ`
+    /// of two non-empty slices, i.e. the coefficients of the product of the polynomials with the coefficients `a` and `b`.
+    ///
+    /// This is a hook for types, that allow a faster multiplication than the generic algorithms used by
+    /// [`Polynomial`](crate::types::Polynomial) (e.g. via a number-theoretic transform). The default implementation
+    /// returns `None`, in which case the generic algorithms are used. Implementations may return `None` as well,
+    /// e.g. if the slices are too short for the fast algorithm to pay off.
+    #[allow(unused_variables)]
+    fn convolve(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        None
     }
+}
+
+/// A [Commutative Ring](https://en.wikipedia.org/wiki/Commutative_ring) is a ring with a commutative multiplication.
+pub trait CommutativeRing: Ring + Commutative {}
+impl<T: Ring + Commutative> CommutativeRing for T {}
 
-    fn mut_add(&self, lhs: &mut Self, rhs: &Self) {
-        self.additive_group().mut_op(lhs, rhs)
+/// A [Division Ring](https://en.wikipedia.org/wiki/Division_ring) is a ring, in which every non-zero element is a unit.
+///
+/// As the multiplication does not need to be commutative, we distinguish between right division
+/// (`a * b⁻¹`, which is what the `/`-operator computes) and left division (`b⁻¹ * a`).
+pub trait DivisionRing: Ring + Div<Output = Self> + DivAssign {
+    /// Returns the multiplicative inverse of a non-zero element.
+    ///
+    /// Panics if called on zero.
+    fn multiplicative_inverse(&self) -> Self {
+        self.inverse().expect("Divide by zero error")
     }
 
-    fn subtract(&self, lhs: &Self, rhs: &Self) -> Self {
-        self.add(lhs, &self.additive_inverse(rhs))
+    /// Computes `lhs * rhs⁻¹`.
+    fn divide(lhs: &Self, rhs: &Self) -> Self {
+        lhs.clone() * rhs.multiplicative_inverse()
     }
 
-    fn mut_subtract(&self, lhs: &mut Self, rhs: &Self) {
-        self.mut_add(lhs, &self.additive_inverse(rhs))
+    /// Computes `rhs⁻¹ * lhs`.
+    fn left_divide(lhs: &Self, rhs: &Self) -> Self {
+        rhs.multiplicative_inverse() * lhs.clone()
     }
+}
 
-    fn multiply(&self, lhs: &Self, rhs: &Self) -> Self {
-        self.multiplicative_group().op(lhs, rhs)
+/// A [Field](https://en.wikipedia.org/wiki/Field_(mathematics)) is a commutative division ring.
+pub trait Field: DivisionRing + Commutative {}
+impl<T: DivisionRing + Commutative> Field for T {}
+
+/// A [Euclidean Domain](https://en.wikipedia.org/wiki/Euclidean_domain) is a commutative ring without zero divisors,
+/// in which a division with remainder is possible.
+///
+/// The remainder has to be "smaller" than the divisor, where the size of an element
+/// is measured by the so called euclidean function. This guarantees that the euclidean algorithm terminates,
+/// which makes the functions in [`crate::util`] (e.g. `gcd`) available for every euclidean domain.
+pub trait EuclideanDomain: CommutativeRing {
+    /// Returns the quotient and remainder of dividing `self` by `rhs`,
+    /// such that `self = q * rhs + r` and `r` is either zero or `r.euclidean_value() < rhs.euclidean_value()`.
+    ///
+    /// Panics if `rhs` is zero.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self);
+
+    /// The euclidean function of this domain.
+    fn euclidean_value(&self) -> u128;
+
+    /// Returns the unit `u`, such that `self * u` is the canonical representative
+    /// of all elements associated to `self` (e.g. the absolute value for integers).
+    fn normalizing_unit(&self) -> Self {
+        Self::one()
     }
 
-    fn mut_multiply(&self, lhs: &mut Self, rhs: &Self) {
-        self.multiplicative_group().mut_op(lhs, rhs)
+    /// Returns the canonical representative of all elements associated to `self`.
+    fn unit_normal(&self) -> Self {
+        self.clone() * self.normalizing_unit()
     }
-}
 
-// ///
-// pub trait Rng: NearRing
-// where
-//     Self::AdditiveGroup: CommutativeGroup,
-// {
-// }
-
-// pub trait Ring: Rng
-// where
-//     Self::AdditiveGroup: CommutativeGroup,
-//     Self::MultiplicativeGroup: Monoid,
-// {
-//     fn multiplicative_identity(&self) -> Self {
-//         self.multiplicative_group().identity()
-//     }
-// }
-
-// pub trait CommutativeRing: Ring
-// where
-//     Self::AdditiveGroup: CommutativeGroup,
-//     Self::MultiplicativeGroup: Monoid + Magma + Commutative,
-// {
-// }
-
-// pub trait DivisionRing: Ring
-// where
-//     Self::AdditiveGroup: CommutativeGroup,
-//     Self::MultiplicativeGroup: Group,
-// {
-//     fn multiplicative_inverse(&self, item: &Self) -> Self {
-//         self.multiplicative_group().inverse(item)
-//     }
-
-//     fn divide(&self, lhs: &Self, rhs: &Self) -> Self {
-//         self.multiply(lhs, &self.multiplicative_inverse(rhs))
-//     }
-
-//     fn mut_divide(&self, lhs: &mut Self, rhs: &Self) {
-//         self.mut_multiply(lhs, &self.multiplicative_inverse(rhs))
-//     }
-// }
-
-// pub trait Field: DivisionRing
-// where
-//     Self::AdditiveGroup: CommutativeGroup,
-//     Self::MultiplicativeGroup: CommutativeGroup,
-// {
-// }
+    /// Checks whether `self` divides `other`.
+    fn divides(&self, other: &Self) -> bool {
+        if self.is_zero() {
+            other.is_zero()
+        } else {
+            other.div_rem(self).1.is_zero()
+        }
+    }
+}
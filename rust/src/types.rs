@@ -1,2 +1,31 @@
+mod complex;
+mod floats;
+mod gaussian;
+mod integers;
+mod laurent;
+mod matrix;
 mod naturals;
+mod polynomial;
+mod quadratic;
+mod quaternion;
+mod rationals;
+mod reduction;
+mod sparse_polynomial;
+mod units;
+mod zmod;
+mod zn;
+pub use complex::*;
+pub use floats::*;
+pub use gaussian::*;
+pub use integers::*;
+pub use laurent::*;
+pub use matrix::*;
 pub use naturals::*;
+pub use polynomial::*;
+pub use quadratic::*;
+pub use quaternion::*;
+pub use rationals::*;
+pub use sparse_polynomial::*;
+pub use units::*;
+pub use zmod::*;
+pub use zn::*;
@@ -0,0 +1,220 @@
+use crate::traits::*;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A complex number `a + bi` with exact coefficients in the field `F`, where `i² = -1`.
+///
+/// This is the quotient ring *F\[x\] / (x² + 1)*, e.g. the Gaussian Rationals *ℚ(i)* for `F = Rational`
+/// or the finite field with `p²` elements for `F = ZMod<p>` with `p ≡ 3 (mod 4)`.
+/// It is a field iff *x² + 1* is irreducible over `F`, i.e. iff `-1` is not a square in `F` (see [`MinusOneSquare`]),
+/// so using the division otherwise fails at compile time.
+/// Otherwise only the elements with a non-zero norm are units (see [`Ring::inverse`]).
+///
+/// ```compile_fail
+/// use polydiv::types::{Complex, ZMod};
+/// // -1 = 2² is a square modulo 5, so there is no division
+/// let _ = Complex::<ZMod<5>>::i() / Complex::<ZMod<5>>::i();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Complex<F> {
+    re: F,
+    im: F,
+}
+
+impl<F> Complex<F> {
+    pub fn new(re: F, im: F) -> Self {
+        Complex { re, im }
+    }
+
+    pub fn re(&self) -> &F {
+        &self.re
+    }
+
+    pub fn im(&self) -> &F {
+        &self.im
+    }
+}
+
+impl<F: Field> Complex<F> {
+    /// The imaginary unit `i`.
+    pub fn i() -> Self {
+        Complex::new(F::zero(), F::one())
+    }
+
+    /// The conjugate `a - bi`.
+    pub fn conjugate(&self) -> Self {
+        Complex::new(self.re.clone(), -self.im.clone())
+    }
+
+    /// The norm `a² + b² = (a + bi)(a - bi)`.
+    pub fn norm(&self) -> F {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+}
+
+impl<F: Field + MinusOneSquare> Complex<F> {
+    const FIELD: () = assert!(
+        !F::IS_SQUARE,
+        "Complex<F> is only a field if -1 is not a square in F"
+    );
+
+    /// Returns whether `Complex<F>` is a field, i.e. whether `-1` is not a square in `F`.
+    pub fn is_field() -> bool {
+        !F::IS_SQUARE
+    }
+}
+
+impl<F: Field> From<F> for Complex<F> {
+    fn from(value: F) -> Self {
+        Complex::new(value, F::zero())
+    }
+}
+
+impl<F: Field + Display> Display for Complex<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im.is_zero() {
+            write!(f, "{}", self.re)
+        } else if self.re.is_zero() && self.im.is_one() {
+            write!(f, "i")
+        } else if self.re.is_zero() {
+            write!(f, "{}i", self.im)
+        } else if self.im.is_one() {
+            write!(f, "{} + i", self.re)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl<F: Field> Add for Complex<F> {
+    type Output = Complex<F>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<F: Field> AddAssign for Complex<F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<F: Field> Sub for Complex<F> {
+    type Output = Complex<F>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<F: Field> SubAssign for Complex<F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<F: Field> Neg for Complex<F> {
+    type Output = Complex<F>;
+    fn neg(self) -> Self::Output {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl<F: Field> Mul for Complex<F> {
+    type Output = Complex<F>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Complex::new(
+            self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone(),
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl<F: Field> MulAssign for Complex<F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+impl<F: Field + MinusOneSquare> Div for Complex<F> {
+    type Output = Complex<F>;
+    fn div(self, rhs: Self) -> Self::Output {
+        DivisionRing::divide(&self, &rhs)
+    }
+}
+
+impl<F: Field + MinusOneSquare> DivAssign for Complex<F> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+impl<F: Field> Set<Complex<F>> for Complex<F> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<F> Associative for Complex<F> {}
+impl<F> Commutative for Complex<F> {}
+impl<F> LeftDistributive for Complex<F> {}
+impl<F> RightDistributive for Complex<F> {}
+
+impl<F> MinusOneSquare for Complex<F> {
+    const IS_SQUARE: bool = true;
+}
+
+impl<F: Field> NearRing for Complex<F> {
+    fn zero() -> Self {
+        Complex::from(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<F: Field> Ring for Complex<F> {
+    fn one() -> Self {
+        Complex::from(F::one())
+    }
+
+    /// Computes the inverse as `conjugate / norm`, i.e. the units are exactly the elements with non-zero norm.
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.norm().inverse()?;
+        Some(Complex::new(
+            self.re.clone() * norm.clone(),
+            -self.im.clone() * norm,
+        ))
+    }
+
+    /// Reduces the convolution to three convolutions of the real and imaginary parts (if those are fast),
+    /// via `(a + bi)(c + di) = ac - bd + ((a + b)(c + d) - ac - bd)i`.
+    fn convolve(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        let parts = |v: &[Self]| -> (Vec<F>, Vec<F>, Vec<F>) {
+            let re: Vec<F> = v.iter().map(|x| x.re.clone()).collect();
+            let im: Vec<F> = v.iter().map(|x| x.im.clone()).collect();
+            let sum = v.iter().map(|x| x.re.clone() + x.im.clone()).collect();
+            (re, im, sum)
+        };
+        let ((a_re, a_im, a_sum), (b_re, b_im, b_sum)) = (parts(a), parts(b));
+        let re_re = F::convolve(&a_re, &b_re)?;
+        let im_im = F::convolve(&a_im, &b_im)?;
+        let sum_sum = F::convolve(&a_sum, &b_sum)?;
+        Some(
+            re_re
+                .into_iter()
+                .zip(im_im)
+                .zip(sum_sum)
+                .map(|((ac, bd), s)| Complex::new(ac.clone() - bd.clone(), s - ac - bd))
+                .collect(),
+        )
+    }
+}
+
+impl<F: Field + MinusOneSquare> DivisionRing for Complex<F> {
+    fn multiplicative_inverse(&self) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::FIELD;
+        self.inverse().expect("Divide by zero error")
+    }
+}
@@ -0,0 +1,147 @@
+//! Floating-point numbers, which can be used as coefficients for the FFT-based multiplication.
+
+use crate::traits::*;
+use crate::util::{fft_convolve, FFT_THRESHOLD};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A floating-point number, which implements the ring traits, so that it can be used for the coefficients
+/// of polynomials and complex numbers (e.g. with the FFT-based multiplication).
+///
+/// Floating-point arithmetic only approximately satisfies the laws of a field (e.g. the addition is not associative
+/// due to rounding), so algorithms relying on exact arithmetic (like euclidean division or comparisons with zero)
+/// are subject to rounding errors. This is why the traits are implemented for this wrapper instead of `f64` itself.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Float(f64);
+
+impl Float {
+    pub fn new(value: f64) -> Self {
+        Float(value)
+    }
+
+    pub fn value(&self) -> f64 {
+        self.0
+    }
+
+    pub fn abs(&self) -> Self {
+        Float(self.0.abs())
+    }
+}
+
+impl From<f64> for Float {
+    fn from(value: f64) -> Self {
+        Float(value)
+    }
+}
+
+impl From<Float> for f64 {
+    fn from(value: Float) -> Self {
+        value.0
+    }
+}
+
+impl Display for Float {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Add for Float {
+    type Output = Float;
+    fn add(self, rhs: Self) -> Self::Output {
+        Float(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Float {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Float {
+    type Output = Float;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Float(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Float {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Float {
+    type Output = Float;
+    fn neg(self) -> Self::Output {
+        Float(-self.0)
+    }
+}
+
+impl Mul for Float {
+    type Output = Float;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Float(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign for Float {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl Div for Float {
+    type Output = Float;
+    fn div(self, rhs: Self) -> Self::Output {
+        Float(self.0 / rhs.0)
+    }
+}
+
+impl DivAssign for Float {
+    fn div_assign(&mut self, rhs: Self) {
+        self.0 /= rhs.0;
+    }
+}
+
+impl Associative for Float {}
+impl Commutative for Float {}
+impl LeftDistributive for Float {}
+impl RightDistributive for Float {}
+impl FormallyReal for Float {}
+
+impl MinusOneSquare for Float {
+    const IS_SQUARE: bool = false;
+}
+
+impl NearRing for Float {
+    fn zero() -> Self {
+        Float(0.0)
+    }
+}
+
+impl Ring for Float {
+    fn one() -> Self {
+        Float(1.0)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        (self.0 != 0.0).then(|| Float(1.0 / self.0))
+    }
+
+    /// Uses the fast Fourier transform for long operands, see [`fft_convolve`].
+    fn convolve(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        (a.len().min(b.len()) >= FFT_THRESHOLD).then(|| fft_convolve(a, b))
+    }
+}
+
+impl DivisionRing for Float {
+    fn divide(lhs: &Self, rhs: &Self) -> Self {
+        *lhs / *rhs
+    }
+
+    fn left_divide(lhs: &Self, rhs: &Self) -> Self {
+        *lhs / *rhs
+    }
+}
@@ -0,0 +1,309 @@
+use super::{Integer, Natural};
+use crate::number_theory::{factor, sqrt_mod_known_prime, Factorization};
+use crate::traits::*;
+use crate::util::gcd;
+use std::fmt::Display;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// A [Gaussian Integer](https://en.wikipedia.org/wiki/Gaussian_integer) `a + bi` with integer parts, i.e. an element of *ℤ\[i\]*.
+///
+/// The Gaussian Integers form a euclidean domain with the norm `a² + b²` as euclidean function.
+/// Unlike the Integers they are not ordered, so they only rely on the euclidean structure,
+/// e.g. when computing a [`gcd`](crate::util::gcd).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct GaussianInteger {
+    re: Integer,
+    im: Integer,
+}
+
+impl GaussianInteger {
+    pub fn new(re: Integer, im: Integer) -> Self {
+        GaussianInteger { re, im }
+    }
+
+    /// The imaginary unit `i`.
+    pub fn i() -> Self {
+        GaussianInteger::new(Integer::zero(), Integer::one())
+    }
+
+    pub fn re(&self) -> Integer {
+        self.re
+    }
+
+    pub fn im(&self) -> Integer {
+        self.im
+    }
+
+    /// The norm `a² + b² = (a + bi)(a - bi)`, which is multiplicative.
+    pub fn norm(&self) -> Integer {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// The complex conjugate `a - bi`.
+    pub fn conjugate(&self) -> Self {
+        GaussianInteger::new(self.re, -self.im)
+    }
+
+    /// Checks whether this is a [Gaussian prime](https://en.wikipedia.org/wiki/Gaussian_integer#Gaussian_primes).
+    ///
+    /// Panics if the norm doesn't fit into 64 bits.
+    pub fn is_prime(&self) -> bool {
+        let norm = u64::try_from(self.norm().value()).expect("The norm has to fit into 64 bits");
+        if self.re.is_zero() || self.im.is_zero() {
+            // Rational primes stay prime iff p = 3 (mod 4)
+            let p = self.re.abs() + self.im.abs();
+            p.value() % 4 == 3 && Natural::new(p.value() as u64).is_prime()
+        } else {
+            Natural::new(norm).is_prime()
+        }
+    }
+
+    /// Factors this Gaussian Integer into Gaussian primes in the first quadrant (see [`EuclideanDomain::unit_normal`])
+    /// and a unit. The primes are sorted by their norm.
+    ///
+    /// The norm is factored over the Integers first: `2` ramifies as `-i(1 + i)²`, primes `p ≡ 3 (mod 4)` stay prime
+    /// and primes `p ≡ 1 (mod 4)` split into `gcd(p, t + i)` and its conjugate, where `t² ≡ -1 (mod p)`.
+    ///
+    /// Panics if this is zero or its norm doesn't fit into 64 bits.
+    pub fn factor(&self) -> Factorization<GaussianInteger> {
+        if self.is_zero() {
+            panic!("Cannot factor zero")
+        }
+        let norm = u64::try_from(self.norm().value()).expect("The norm has to fit into 64 bits");
+        let mut rest = *self;
+        let mut factorization = Factorization::new();
+        let mut push = |prime: GaussianInteger, exp: u32, rest: &mut GaussianInteger| {
+            for _ in 0..exp {
+                *rest = rest.divide_exact(&prime);
+            }
+            factorization.push(prime, exp);
+        };
+
+        for (p, e) in factor(Natural::new(norm)) {
+            let p = p.value();
+            let p_int = GaussianInteger::from(p);
+            match p % 4 {
+                2 => push(GaussianInteger::from((1, 1)), e, &mut rest),
+                3 => push(p_int, e / 2, &mut rest),
+                _ => {
+                    let t = sqrt_mod_known_prime(Natural::new(p - 1), Natural::new(p)).unwrap();
+                    let pi = gcd(p_int, GaussianInteger::from((t.value() as i64, 1)));
+                    let pi_bar = pi.conjugate().unit_normal();
+                    let mut k = 0;
+                    let mut test = rest;
+                    while k < e && pi.divides(&test) {
+                        test = test.divide_exact(&pi);
+                        k += 1;
+                    }
+                    push(pi, k, &mut rest);
+                    push(pi_bar, e - k, &mut rest);
+                }
+            }
+        }
+
+        if !rest.is_one() {
+            factorization.set_unit(Some(rest));
+        }
+        factorization.sort_by_key(|p| (p.norm(), p.re, p.im));
+        factorization
+    }
+
+    fn divide_exact(&self, rhs: &Self) -> Self {
+        let (q, r) = self.div_rem(rhs);
+        debug_assert!(r.is_zero());
+        q
+    }
+}
+
+impl From<Integer> for GaussianInteger {
+    fn from(value: Integer) -> Self {
+        GaussianInteger::new(value, Integer::zero())
+    }
+}
+
+impl From<u64> for GaussianInteger {
+    fn from(value: u64) -> Self {
+        GaussianInteger::from(Integer::from(value))
+    }
+}
+
+impl From<i64> for GaussianInteger {
+    fn from(value: i64) -> Self {
+        GaussianInteger::from(Integer::from(value))
+    }
+}
+
+/// Creates `a + bi` from the pair `(a, b)`.
+impl From<(i64, i64)> for GaussianInteger {
+    fn from((re, im): (i64, i64)) -> Self {
+        GaussianInteger::new(Integer::from(re), Integer::from(im))
+    }
+}
+
+impl Display for GaussianInteger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let im = match self.im.value() {
+            1 => "i".to_string(),
+            -1 => "-i".to_string(),
+            b => format!("{b}i"),
+        };
+        if self.im.is_zero() {
+            write!(f, "{}", self.re)
+        } else if self.re.is_zero() {
+            write!(f, "{im}")
+        } else if self.im.is_negative() {
+            write!(f, "{} - {}", self.re, &im[1..])
+        } else {
+            write!(f, "{} + {im}", self.re)
+        }
+    }
+}
+
+impl Add for GaussianInteger {
+    type Output = GaussianInteger;
+    fn add(self, rhs: Self) -> Self::Output {
+        GaussianInteger::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl AddAssign for GaussianInteger {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for GaussianInteger {
+    type Output = GaussianInteger;
+    fn sub(self, rhs: Self) -> Self::Output {
+        GaussianInteger::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl SubAssign for GaussianInteger {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for GaussianInteger {
+    type Output = GaussianInteger;
+    fn neg(self) -> Self::Output {
+        GaussianInteger::new(-self.re, -self.im)
+    }
+}
+
+impl Mul for GaussianInteger {
+    type Output = GaussianInteger;
+    fn mul(self, rhs: Self) -> Self::Output {
+        GaussianInteger::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re,
+        )
+    }
+}
+
+impl MulAssign for GaussianInteger {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// The quotient of the euclidean division, see [`EuclideanDomain::div_rem`].
+impl Div for GaussianInteger {
+    type Output = GaussianInteger;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl DivAssign for GaussianInteger {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// The remainder of the euclidean division, see [`EuclideanDomain::div_rem`].
+impl Rem for GaussianInteger {
+    type Output = GaussianInteger;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl RemAssign for GaussianInteger {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl Set<GaussianInteger> for GaussianInteger {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl Associative for GaussianInteger {}
+impl Commutative for GaussianInteger {}
+impl LeftDistributive for GaussianInteger {}
+impl RightDistributive for GaussianInteger {}
+
+impl NearRing for GaussianInteger {
+    fn zero() -> Self {
+        GaussianInteger::new(Integer::zero(), Integer::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl Ring for GaussianInteger {
+    fn one() -> Self {
+        GaussianInteger::new(Integer::one(), Integer::zero())
+    }
+
+    /// The units are `1`, `-1`, `i` and `-i`, which are exactly the elements of norm 1.
+    fn inverse(&self) -> Option<Self> {
+        if self.norm().is_one() {
+            Some(self.conjugate())
+        } else {
+            None
+        }
+    }
+}
+
+impl EuclideanDomain for GaussianInteger {
+    /// Divides by rounding the exact quotient `self / rhs` in *ℚ(i)* to the nearest Gaussian Integer.
+    /// The remainder then has at most half the norm of `rhs`.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let norm = rhs.norm();
+        if norm.is_zero() {
+            panic!("Divide by zero error")
+        }
+        let numer = *self * rhs.conjugate();
+        // ⌊x / n + 1/2⌋ = ⌊(2x + n) / 2n⌋ rounds to the nearest integer
+        let round = |x: Integer| (x + x + norm) / (norm + norm);
+        let quot = GaussianInteger::new(round(numer.re), round(numer.im));
+        (quot, *self - quot * *rhs)
+    }
+
+    fn euclidean_value(&self) -> u128 {
+        self.norm().value() as u128
+    }
+
+    /// Rotates into the first quadrant, i.e. `a > 0` and `b >= 0` for the normalized element `a + bi`.
+    fn normalizing_unit(&self) -> Self {
+        let (a, b) = (self.re.value(), self.im.value());
+        if (a > 0 && b >= 0) || self.is_zero() {
+            Self::one()
+        } else if a <= 0 && b > 0 {
+            -Self::i()
+        } else if a < 0 && b <= 0 {
+            -Self::one()
+        } else {
+            Self::i()
+        }
+    }
+}
@@ -0,0 +1,217 @@
+use super::Natural;
+use crate::traits::*;
+use crate::util::{ntt_convolve_integers, NTT_THRESHOLD};
+use std::fmt::Display;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use std::str::FromStr;
+
+/// An element of the Integers *ℤ*.
+///
+/// The value is stored as an `i128`, so that every [`Natural`] can be represented as an Integer as well.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Integer(i128);
+
+impl Integer {
+    pub fn new(value: i128) -> Self {
+        Integer(value)
+    }
+
+    pub fn value(&self) -> i128 {
+        self.0
+    }
+
+    pub fn abs(&self) -> Self {
+        Integer(self.0.abs())
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of this Integer.
+    pub fn signum(&self) -> Self {
+        Integer(self.0.signum())
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0 < 0
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.0 > 0
+    }
+
+    pub fn pow(&self, exp: u32) -> Self {
+        Integer(self.0.pow(exp))
+    }
+}
+
+impl From<i128> for Integer {
+    fn from(value: i128) -> Self {
+        Integer(value)
+    }
+}
+
+impl From<i64> for Integer {
+    fn from(value: i64) -> Self {
+        Integer(value as i128)
+    }
+}
+
+impl From<u64> for Integer {
+    fn from(value: u64) -> Self {
+        Integer(value as i128)
+    }
+}
+
+impl From<Natural> for Integer {
+    fn from(value: Natural) -> Self {
+        Integer(value.value() as i128)
+    }
+}
+
+impl Display for Integer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Integer {
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.trim().parse().map(Integer)
+    }
+}
+
+impl Add for Integer {
+    type Output = Integer;
+    fn add(self, rhs: Self) -> Self::Output {
+        Integer(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Integer {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Integer {
+    type Output = Integer;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Integer(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Integer {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Integer {
+    type Output = Integer;
+    fn neg(self) -> Self::Output {
+        Integer(-self.0)
+    }
+}
+
+impl Mul for Integer {
+    type Output = Integer;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Integer(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign for Integer {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+/// Euclidean division, i.e. the remainder of `a / b` is always non-negative.
+impl Div for Integer {
+    type Output = Integer;
+    fn div(self, rhs: Self) -> Self::Output {
+        Integer(self.0.div_euclid(rhs.0))
+    }
+}
+
+impl DivAssign for Integer {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Euclidean remainder, i.e. `a % b` is always non-negative.
+impl Rem for Integer {
+    type Output = Integer;
+    fn rem(self, rhs: Self) -> Self::Output {
+        Integer(self.0.rem_euclid(rhs.0))
+    }
+}
+
+impl RemAssign for Integer {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = *self % rhs;
+    }
+}
+
+impl Set<Integer> for Integer {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl Associative for Integer {}
+impl Commutative for Integer {}
+impl LeftDistributive for Integer {}
+impl RightDistributive for Integer {}
+
+impl NearRing for Integer {
+    fn zero() -> Self {
+        Integer(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl Ring for Integer {
+    fn one() -> Self {
+        Integer(1)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.0 == 1 || self.0 == -1 {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+
+    /// Uses number-theoretic transforms modulo several primes for long operands, see [`ntt_convolve_integers`].
+    fn convolve(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        if a.len().min(b.len()) < NTT_THRESHOLD {
+            return None;
+        }
+        ntt_convolve_integers(a, b)
+    }
+}
+
+impl EuclideanDomain for Integer {
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        (*self / *rhs, *self % *rhs)
+    }
+
+    fn euclidean_value(&self) -> u128 {
+        self.0.unsigned_abs()
+    }
+
+    fn normalizing_unit(&self) -> Self {
+        if self.0 < 0 {
+            Integer(-1)
+        } else {
+            Integer(1)
+        }
+    }
+}
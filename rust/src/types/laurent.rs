@@ -0,0 +1,439 @@
+use super::polynomial::format_term;
+use super::{Polynomial, Variable, X};
+use crate::traits::*;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LaurentErr {
+    /// The Laurent polynomial contains negative powers, so it isn't a polynomial.
+    NegativePowers,
+    /// The denominator of a rational function is zero.
+    ZeroDenominator,
+    /// The rational function isn't a Laurent polynomial, i.e. its reduced denominator isn't a power of the variable.
+    NotLaurent,
+}
+
+impl Display for LaurentErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LaurentErr::NegativePowers => {
+                write!(f, "the Laurent polynomial contains negative powers")
+            }
+            LaurentErr::ZeroDenominator => write!(f, "the denominator is zero"),
+            LaurentErr::NotLaurent => {
+                write!(f, "the denominator is not a power of the variable")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LaurentErr {}
+
+/// A [Laurent polynomial](https://en.wikipedia.org/wiki/Laurent_polynomial) `aₘxᵐ + ... + aₙxⁿ` with coefficients
+/// in the ring `R`, where the powers `m ≤ n` may be negative.
+///
+/// Laurent polynomials are the [localization](https://en.wikipedia.org/wiki/Localization_(commutative_algebra)) of the
+/// polynomials at the variable, i.e. the rational functions, whose denominator is a power of `x`. Unlike for
+/// [`Polynomial`], multiplying with `x^k` can always be undone, so [`LaurentPolynomial::shift`] is lossless.
+///
+/// The coefficients are stored densely, starting with the coefficient of the lowest power `x^valuation`.
+/// Laurent polynomials are always normalized, i.e. the coefficients of the lowest and highest powers are non-zero.
+pub struct LaurentPolynomial<R, V = X> {
+    coefs: Vec<R>,
+    valuation: isize,
+    variable: PhantomData<V>,
+}
+
+impl<R: Ring, V: Variable> LaurentPolynomial<R, V> {
+    /// Creates the zero Laurent polynomial.
+    pub fn new() -> Self {
+        LaurentPolynomial::from_vec(Vec::new(), 0)
+    }
+
+    /// Creates the Laurent polynomial `a₀x^valuation + a₁x^(valuation + 1) + ...` from its coefficients `[a₀, a₁, ...]`.
+    pub fn from_vec(coefs: Vec<R>, valuation: isize) -> Self {
+        let mut p = LaurentPolynomial {
+            coefs,
+            valuation,
+            variable: PhantomData,
+        };
+        p.normalize();
+        p
+    }
+
+    /// Creates the monomial `coef * x^pow`.
+    pub fn monomial(coef: R, pow: isize) -> Self {
+        LaurentPolynomial::from_vec(vec![coef], pow)
+    }
+
+    /// Returns the Laurent polynomial `x`.
+    pub fn x() -> Self {
+        LaurentPolynomial::monomial(R::one(), 1)
+    }
+
+    /// Returns the coefficients, starting with the coefficient of the lowest power.
+    pub fn coefs(&self) -> &Vec<R> {
+        &self.coefs
+    }
+
+    /// Returns the lowest power with a non-zero coefficient, or `None` for the zero Laurent polynomial.
+    pub fn valuation(&self) -> Option<isize> {
+        (!self.coefs.is_empty()).then_some(self.valuation)
+    }
+
+    /// Returns the highest power with a non-zero coefficient, or `None` for the zero Laurent polynomial.
+    pub fn degree(&self) -> Option<isize> {
+        (!self.coefs.is_empty()).then(|| self.valuation + self.coefs.len() as isize - 1)
+    }
+
+    /// Returns the coefficient of `x^pow`.
+    pub fn coef(&self, pow: isize) -> R {
+        usize::try_from(pow - self.valuation)
+            .ok()
+            .and_then(|i| self.coefs.get(i).cloned())
+            .unwrap_or_else(R::zero)
+    }
+
+    /// Iterates over the non-zero terms as pairs `(pow, coef)` in ascending order of their powers.
+    pub fn terms(&self) -> impl Iterator<Item = (isize, &R)> {
+        self.coefs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| !c.is_zero())
+            .map(|(i, c)| (self.valuation + i as isize, c))
+    }
+
+    /// Checks whether there are no negative powers, i.e. whether this is an ordinary polynomial.
+    pub fn is_polynomial(&self) -> bool {
+        self.valuation().is_none_or(|v| v >= 0)
+    }
+
+    /// Multiplies with `x^amount`, where `amount` might be negative.
+    pub fn shift(&mut self, amount: isize) -> &mut Self {
+        if !self.coefs.is_empty() {
+            self.valuation += amount;
+        }
+        self
+    }
+
+    /// Splits this Laurent polynomial into a numerator and a denominator `x^k`, which have no common factor `x`.
+    ///
+    /// This is the representation as a rational function, see [`LaurentPolynomial::from_fraction`] for the reverse.
+    pub fn to_fraction(&self) -> (Polynomial<R, V>, Polynomial<R, V>) {
+        let den_pow = (-self.valuation).max(0) as usize;
+        let mut num = Polynomial::from_vec(self.coefs.clone());
+        num.shl((self.valuation + den_pow as isize) as usize);
+        (num, Polynomial::monomial(R::one(), den_pow))
+    }
+
+    fn normalize(&mut self) {
+        while self.coefs.last().is_some_and(|c| c.is_zero()) {
+            self.coefs.pop();
+        }
+        let leading_zeros = self.coefs.iter().take_while(|c| c.is_zero()).count();
+        self.coefs.drain(0..leading_zeros);
+        self.valuation = if self.coefs.is_empty() {
+            0
+        } else {
+            self.valuation + leading_zeros as isize
+        };
+    }
+}
+
+impl<R: DivisionRing, V: Variable> LaurentPolynomial<R, V> {
+    /// Evaluates the Laurent polynomial at a non-zero `x`.
+    ///
+    /// Panics if `x` is zero and there are negative powers.
+    pub fn eval(&self, x: &R) -> R {
+        let value = Polynomial::<R, V>::from_vec(self.coefs.clone()).eval(x);
+        let factor = if self.valuation < 0 {
+            x.multiplicative_inverse()
+        } else {
+            x.clone()
+        };
+        (0..self.valuation.unsigned_abs()).fold(value, |acc, _| acc * factor.clone())
+    }
+}
+
+impl<F: Field, V: Variable> LaurentPolynomial<F, V> {
+    /// Converts the rational function `num / den` into a Laurent polynomial.
+    ///
+    /// This succeeds iff the reduced denominator is a power of `x`, i.e. iff `den = x^k * d` with `d` dividing `num`.
+    pub fn from_fraction(
+        num: &Polynomial<F, V>,
+        den: &Polynomial<F, V>,
+    ) -> Result<Self, LaurentErr> {
+        if den.is_zero() {
+            return Err(LaurentErr::ZeroDenominator);
+        }
+        let den_pow = den.terms().next().map_or(0, |(pow, _)| pow);
+        let mut d = den.clone();
+        d.shr(den_pow);
+        let (quot, rem) = num.div_rem(&d);
+        if !rem.is_zero() {
+            return Err(LaurentErr::NotLaurent);
+        }
+        let mut p = LaurentPolynomial::from(quot);
+        p.shift(-(den_pow as isize));
+        Ok(p)
+    }
+}
+
+// Implemented manually, so that the variable marker doesn't need to implement these traits
+
+impl<R: Clone, V> Clone for LaurentPolynomial<R, V> {
+    fn clone(&self) -> Self {
+        LaurentPolynomial {
+            coefs: self.coefs.clone(),
+            valuation: self.valuation,
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: PartialEq, V> PartialEq for LaurentPolynomial<R, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.valuation == other.valuation && self.coefs == other.coefs
+    }
+}
+
+impl<R: Eq, V> Eq for LaurentPolynomial<R, V> {}
+
+impl<R: Hash, V> Hash for LaurentPolynomial<R, V> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.coefs.hash(state);
+        self.valuation.hash(state);
+    }
+}
+
+impl<R: Debug, V: Variable> Debug for LaurentPolynomial<R, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LaurentPolynomial")
+            .field("coefs", &self.coefs)
+            .field("valuation", &self.valuation)
+            .field("variable", &V::NAME)
+            .finish()
+    }
+}
+
+impl<R: Ring, V: Variable> Default for LaurentPolynomial<R, V> {
+    fn default() -> Self {
+        LaurentPolynomial::new()
+    }
+}
+
+impl<R: Ring, V: Variable> From<R> for LaurentPolynomial<R, V> {
+    fn from(value: R) -> Self {
+        LaurentPolynomial::monomial(value, 0)
+    }
+}
+
+impl<R: Ring, V: Variable> From<Polynomial<R, V>> for LaurentPolynomial<R, V> {
+    fn from(value: Polynomial<R, V>) -> Self {
+        LaurentPolynomial::from_vec(value.into_coefs(), 0)
+    }
+}
+
+impl<R: Ring, V: Variable> TryFrom<LaurentPolynomial<R, V>> for Polynomial<R, V> {
+    type Error = LaurentErr;
+    fn try_from(value: LaurentPolynomial<R, V>) -> Result<Self, Self::Error> {
+        if !value.is_polynomial() {
+            return Err(LaurentErr::NegativePowers);
+        }
+        let mut p = Polynomial::from_vec(value.coefs);
+        p.shl(value.valuation as usize);
+        Ok(p)
+    }
+}
+
+/// Shows the terms in descending order like [`Polynomial`], with negative powers in parentheses, e.g. `x + 2 + 3x^(-1)`.
+impl<R: Ring + Display, V: Variable> Display for LaurentPolynomial<R, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", R::zero());
+        }
+        let terms: Vec<String> = self
+            .terms()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(|(pow, coef)| match pow {
+                0 => format_term(coef, None),
+                1 => format_term(coef, Some(V::NAME)),
+                pow if pow < 0 => format_term(coef, Some(&format!("{}^({pow})", V::NAME))),
+                pow => format_term(coef, Some(&format!("{}^{pow}", V::NAME))),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+impl<R: Ring, V: Variable> AddAssign<&LaurentPolynomial<R, V>> for LaurentPolynomial<R, V> {
+    fn add_assign(&mut self, rhs: &LaurentPolynomial<R, V>) {
+        self.combine(rhs, |c1, c2| *c1 += c2);
+    }
+}
+
+impl<R: Ring, V: Variable> AddAssign for LaurentPolynomial<R, V> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Add<&LaurentPolynomial<R, V>> for &LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn add(self, rhs: &LaurentPolynomial<R, V>) -> Self::Output {
+        let mut p = self.clone();
+        p += rhs;
+        p
+    }
+}
+
+impl<R: Ring, V: Variable> Add for LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> SubAssign<&LaurentPolynomial<R, V>> for LaurentPolynomial<R, V> {
+    fn sub_assign(&mut self, rhs: &LaurentPolynomial<R, V>) {
+        self.combine(rhs, |c1, c2| *c1 -= c2);
+    }
+}
+
+impl<R: Ring, V: Variable> SubAssign for LaurentPolynomial<R, V> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Sub<&LaurentPolynomial<R, V>> for &LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn sub(self, rhs: &LaurentPolynomial<R, V>) -> Self::Output {
+        let mut p = self.clone();
+        p -= rhs;
+        p
+    }
+}
+
+impl<R: Ring, V: Variable> Sub for LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> LaurentPolynomial<R, V> {
+    /// Adds (or subtracts) the coefficients of `rhs` to the ones of the same power of `self`.
+    fn combine(&mut self, rhs: &Self, op: impl Fn(&mut R, R)) {
+        if rhs.coefs.is_empty() {
+            return;
+        }
+        if self.coefs.is_empty() {
+            self.valuation = rhs.valuation;
+        }
+        if rhs.valuation < self.valuation {
+            let prefix = (self.valuation - rhs.valuation) as usize;
+            self.coefs.splice(0..0, vec![R::zero(); prefix]);
+            self.valuation = rhs.valuation;
+        }
+        let offset = (rhs.valuation - self.valuation) as usize;
+        if self.coefs.len() < offset + rhs.coefs.len() {
+            self.coefs.resize(offset + rhs.coefs.len(), R::zero());
+        }
+        for (c1, c2) in self.coefs[offset..].iter_mut().zip(&rhs.coefs) {
+            op(c1, c2.clone());
+        }
+        self.normalize();
+    }
+}
+
+impl<R: Ring, V: Variable> Neg for &LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn neg(self) -> Self::Output {
+        LaurentPolynomial::from_vec(
+            self.coefs.iter().map(|c| -c.clone()).collect(),
+            self.valuation,
+        )
+    }
+}
+
+impl<R: Ring, V: Variable> Neg for LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<R: Ring, V: Variable> Mul<&LaurentPolynomial<R, V>> for &LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn mul(self, rhs: &LaurentPolynomial<R, V>) -> Self::Output {
+        let product = &Polynomial::<R, V>::from_vec(self.coefs.clone())
+            * &Polynomial::from_vec(rhs.coefs.clone());
+        LaurentPolynomial::from_vec(product.into_coefs(), self.valuation + rhs.valuation)
+    }
+}
+
+impl<R: Ring, V: Variable> Mul for LaurentPolynomial<R, V> {
+    type Output = LaurentPolynomial<R, V>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<R: Ring, V: Variable> MulAssign<&LaurentPolynomial<R, V>> for LaurentPolynomial<R, V> {
+    fn mul_assign(&mut self, rhs: &LaurentPolynomial<R, V>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> MulAssign for LaurentPolynomial<R, V> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Set<LaurentPolynomial<R, V>> for LaurentPolynomial<R, V> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<R: Ring, V: Variable> Associative for LaurentPolynomial<R, V> {}
+impl<R: Ring + Commutative, V: Variable> Commutative for LaurentPolynomial<R, V> {}
+impl<R: Ring, V: Variable> LeftDistributive for LaurentPolynomial<R, V> {}
+impl<R: Ring, V: Variable> RightDistributive for LaurentPolynomial<R, V> {}
+
+impl<R: Ring, V: Variable> NearRing for LaurentPolynomial<R, V> {
+    fn zero() -> Self {
+        LaurentPolynomial::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefs.is_empty()
+    }
+}
+
+impl<R: Ring, V: Variable> Ring for LaurentPolynomial<R, V> {
+    fn one() -> Self {
+        LaurentPolynomial::from(R::one())
+    }
+
+    /// Returns the inverse of monomials `c * x^k` with a unit coefficient `c`, which is `c⁻¹ * x^(-k)`.
+    ///
+    /// Over an integral domain these are all units; like for [`Polynomial`], other units are not detected.
+    fn inverse(&self) -> Option<Self> {
+        match self.coefs.as_slice() {
+            [c] => c
+                .inverse()
+                .map(|inv| LaurentPolynomial::monomial(inv, -self.valuation)),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,345 @@
+use crate::traits::*;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A square `N×N` [matrix](https://en.wikipedia.org/wiki/Matrix_(mathematics)) with entries in the ring `R`.
+///
+/// The size is part of the type, so that the matrices of a fixed size over a commutative ring form a ring
+/// with [`Matrix::identity`] as one. This ring is not commutative for `N > 1`, even if the entries are.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<R, const N: usize> {
+    rows: [[R; N]; N],
+}
+
+impl<R: Ring, const N: usize> Matrix<R, N> {
+    /// Creates a matrix from its rows.
+    pub fn new(rows: [[R; N]; N]) -> Self {
+        Matrix { rows }
+    }
+
+    /// Creates a matrix, whose entry in row `i` and column `j` is `f(i, j)`.
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> R) -> Self {
+        Matrix {
+            rows: std::array::from_fn(|i| std::array::from_fn(|j| f(i, j))),
+        }
+    }
+
+    /// Creates the diagonal matrix with `value` on its diagonal.
+    pub fn scalar(value: R) -> Self {
+        Matrix::from_fn(|i, j| if i == j { value.clone() } else { R::zero() })
+    }
+
+    pub fn identity() -> Self {
+        Matrix::scalar(R::one())
+    }
+
+    pub fn rows(&self) -> &[[R; N]; N] {
+        &self.rows
+    }
+
+    pub fn transpose(&self) -> Self {
+        Matrix::from_fn(|i, j| self.rows[j][i].clone())
+    }
+
+    /// Returns the sum of the diagonal entries.
+    pub fn trace(&self) -> R {
+        (0..N).fold(R::zero(), |acc, i| acc + self.rows[i][i].clone())
+    }
+
+    /// Multiplies every entry with `value` from the right.
+    pub fn scale(&self, value: &R) -> Self {
+        Matrix::from_fn(|i, j| self.rows[i][j].clone() * value.clone())
+    }
+
+    /// Inverts the matrix via [Gauss-Jordan elimination](https://en.wikipedia.org/wiki/Gaussian_elimination),
+    /// where only units are used as pivots.
+    ///
+    /// Over a division ring this finds the inverse of every invertible matrix. Over other rings some invertible matrices
+    /// can't be reduced this way (e.g. `[[2, 3], [3, 5]]` over the Integers), in which case `None` is returned as well.
+    /// See [`Matrix::adjugate`] for an inversion that works over every commutative ring.
+    fn gauss_jordan_inverse(&self) -> Option<Self> {
+        let mut left = self.rows.clone();
+        let mut right = Matrix::<R, N>::identity().rows;
+        for col in 0..N {
+            let (pivot_row, pivot_inv) =
+                (col..N).find_map(|row| left[row][col].inverse().map(|inv| (row, inv)))?;
+            left.swap(col, pivot_row);
+            right.swap(col, pivot_row);
+            for j in 0..N {
+                left[col][j] = pivot_inv.clone() * left[col][j].clone();
+                right[col][j] = pivot_inv.clone() * right[col][j].clone();
+            }
+            for row in (0..N).filter(|row| *row != col) {
+                let factor = left[row][col].clone();
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in 0..N {
+                    left[row][j] -= factor.clone() * left[col][j].clone();
+                    right[row][j] -= factor.clone() * right[col][j].clone();
+                }
+            }
+        }
+        Some(Matrix { rows: right })
+    }
+}
+
+impl<R: CommutativeRing, const N: usize> Matrix<R, N> {
+    /// Returns the [adjugate](https://en.wikipedia.org/wiki/Adjugate_matrix) `adj(A)`, i.e. the transposed cofactor matrix,
+    /// which satisfies `A * adj(A) = det(A) * I`.
+    ///
+    /// It is computed via the Cayley-Hamilton theorem from the characteristic polynomial, so no divisions are needed.
+    pub fn adjugate(&self) -> Self {
+        self.adjugate_and_determinant().0
+    }
+
+    /// If `det(xI - A) = xᴺ + cₙ₋₁xᴺ⁻¹ + ... + c₀`, then `det(A) = (-1)ᴺc₀`
+    /// and `adj(A) = (-1)ᴺ⁻¹(Aᴺ⁻¹ + cₙ₋₁Aᴺ⁻² + ... + c₁I)`, which is evaluated with Horner's method.
+    fn adjugate_and_determinant(&self) -> (Self, R) {
+        let coefs = self.characteristic_coefs();
+        let mut adj = Matrix::identity();
+        for c in coefs.iter().take(N).skip(1) {
+            adj = &(&adj * self) + &Matrix::scalar(c.clone());
+        }
+        let det = coefs[N].clone();
+        if N.is_multiple_of(2) {
+            (-adj, det)
+        } else {
+            (adj, -det)
+        }
+    }
+
+    /// Returns the coefficients of the characteristic polynomial `det(xI - A)`, starting with the highest power.
+    ///
+    /// They are computed with the [Samuelson-Berkowitz algorithm](https://en.wikipedia.org/wiki/Samuelson%E2%80%93Berkowitz_algorithm),
+    /// which extends the polynomial of the leading `r×r` block `M` to the next block by multiplying with the Toeplitz matrix
+    /// of `1, -aᵣᵣ, -R·C, -R·M·C, ..., -R·Mʳ⁻¹·C`, where `R` and `C` are the row and column next to `M`.
+    fn characteristic_coefs(&self) -> Vec<R> {
+        let a = &self.rows;
+        let mut coefs = vec![R::one()];
+        for r in 0..N {
+            let mut toeplitz = vec![R::one(), -a[r][r].clone()];
+            let mut col: Vec<R> = (0..r).map(|i| a[i][r].clone()).collect();
+            for _ in 0..r {
+                let dot = (0..r).fold(R::zero(), |acc, j| acc + a[r][j].clone() * col[j].clone());
+                toeplitz.push(-dot);
+                col = (0..r)
+                    .map(|i| {
+                        (0..r).fold(R::zero(), |acc, j| acc + a[i][j].clone() * col[j].clone())
+                    })
+                    .collect();
+            }
+            coefs = (0..r + 2)
+                .map(|i| {
+                    (0..=i.min(r)).fold(R::zero(), |acc, j| {
+                        acc + toeplitz[i - j].clone() * coefs[j].clone()
+                    })
+                })
+                .collect();
+        }
+        coefs
+    }
+}
+
+impl<F: Field, const N: usize> Matrix<F, N> {
+    /// Computes the [determinant](https://en.wikipedia.org/wiki/Determinant) via Gaussian elimination.
+    pub fn determinant(&self) -> F {
+        let mut rows = self.rows.clone();
+        let mut det = F::one();
+        for col in 0..N {
+            let Some(pivot_row) = (col..N).find(|row| !rows[*row][col].is_zero()) else {
+                return F::zero();
+            };
+            if pivot_row != col {
+                rows.swap(col, pivot_row);
+                det = -det;
+            }
+            let (upper, lower) = rows.split_at_mut(col + 1);
+            let pivot_row = &upper[col];
+            let pivot = pivot_row[col].clone();
+            det *= pivot.clone();
+            for row in lower {
+                let factor = row[col].clone() / pivot.clone();
+                for (e, p) in row.iter_mut().zip(pivot_row).skip(col) {
+                    *e -= factor.clone() * p.clone();
+                }
+            }
+        }
+        det
+    }
+}
+
+impl<R, const N: usize> Index<(usize, usize)> for Matrix<R, N> {
+    type Output = R;
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        &self.rows[row][col]
+    }
+}
+
+impl<R, const N: usize> IndexMut<(usize, usize)> for Matrix<R, N> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        &mut self.rows[row][col]
+    }
+}
+
+impl<R: Ring, const N: usize> From<[[R; N]; N]> for Matrix<R, N> {
+    fn from(rows: [[R; N]; N]) -> Self {
+        Matrix::new(rows)
+    }
+}
+
+/// Shows the matrix row by row, e.g. `[[1, 2], [3, 4]]`.
+impl<R: Display, const N: usize> Display for Matrix<R, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let entries: Vec<String> = row.iter().map(|e| e.to_string()).collect();
+                format!("[{}]", entries.join(", "))
+            })
+            .collect();
+        write!(f, "[{}]", rows.join(", "))
+    }
+}
+
+impl<R: Ring, const N: usize> AddAssign<&Matrix<R, N>> for Matrix<R, N> {
+    fn add_assign(&mut self, rhs: &Matrix<R, N>) {
+        for (row, rhs_row) in self.rows.iter_mut().zip(&rhs.rows) {
+            for (e, rhs_e) in row.iter_mut().zip(rhs_row) {
+                *e += rhs_e.clone();
+            }
+        }
+    }
+}
+
+impl<R: Ring, const N: usize> AddAssign for Matrix<R, N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<R: Ring, const N: usize> Add<&Matrix<R, N>> for &Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn add(self, rhs: &Matrix<R, N>) -> Self::Output {
+        let mut m = self.clone();
+        m += rhs;
+        m
+    }
+}
+
+impl<R: Ring, const N: usize> Add for Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl<R: Ring, const N: usize> SubAssign<&Matrix<R, N>> for Matrix<R, N> {
+    fn sub_assign(&mut self, rhs: &Matrix<R, N>) {
+        for (row, rhs_row) in self.rows.iter_mut().zip(&rhs.rows) {
+            for (e, rhs_e) in row.iter_mut().zip(rhs_row) {
+                *e -= rhs_e.clone();
+            }
+        }
+    }
+}
+
+impl<R: Ring, const N: usize> SubAssign for Matrix<R, N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<R: Ring, const N: usize> Sub<&Matrix<R, N>> for &Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn sub(self, rhs: &Matrix<R, N>) -> Self::Output {
+        let mut m = self.clone();
+        m -= rhs;
+        m
+    }
+}
+
+impl<R: Ring, const N: usize> Sub for Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<R: Ring, const N: usize> Neg for &Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn neg(self) -> Self::Output {
+        Matrix::from_fn(|i, j| -self.rows[i][j].clone())
+    }
+}
+
+impl<R: Ring, const N: usize> Neg for Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<R: Ring, const N: usize> Mul<&Matrix<R, N>> for &Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn mul(self, rhs: &Matrix<R, N>) -> Self::Output {
+        Matrix::from_fn(|i, j| {
+            (0..N).fold(R::zero(), |acc, k| {
+                acc + self.rows[i][k].clone() * rhs.rows[k][j].clone()
+            })
+        })
+    }
+}
+
+impl<R: Ring, const N: usize> Mul for Matrix<R, N> {
+    type Output = Matrix<R, N>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<R: Ring, const N: usize> MulAssign<&Matrix<R, N>> for Matrix<R, N> {
+    fn mul_assign(&mut self, rhs: &Matrix<R, N>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<R: Ring, const N: usize> MulAssign for Matrix<R, N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+impl<R: Ring, const N: usize> Associative for Matrix<R, N> {}
+impl<R: Ring, const N: usize> LeftDistributive for Matrix<R, N> {}
+impl<R: Ring, const N: usize> RightDistributive for Matrix<R, N> {}
+
+impl<R: Ring, const N: usize> NearRing for Matrix<R, N> {
+    fn zero() -> Self {
+        Matrix::scalar(R::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.rows.iter().flatten().all(|e| e.is_zero())
+    }
+}
+
+impl<R: CommutativeRing, const N: usize> Ring for Matrix<R, N> {
+    fn one() -> Self {
+        Matrix::identity()
+    }
+
+    /// A matrix is invertible iff its determinant is a unit, in which case the inverse is `det(A)⁻¹ * adj(A)`.
+    ///
+    /// Gauss-Jordan elimination is tried first, as it is faster and finds every inverse over a field.
+    /// Only if it fails (which over a field means that the matrix is singular) the adjugate is computed.
+    fn inverse(&self) -> Option<Self> {
+        self.gauss_jordan_inverse().or_else(|| {
+            let (adj, det) = self.adjugate_and_determinant();
+            det.inverse().map(|inv| adj.scale(&inv))
+        })
+    }
+}
@@ -1,9 +1,46 @@
+use crate::number_theory::{factor, is_prime_u64, Factorization};
 use crate::traits::*;
+use std::fmt::Display;
 use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Natural(u64);
 
+impl Natural {
+    pub fn new(value: u64) -> Self {
+        Natural(value)
+    }
+
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+
+    /// Checks whether this Natural is prime via a Miller-Rabin test.
+    ///
+    /// The test uses a fixed set of bases, which is known to have no strong pseudoprimes below `2^64`,
+    /// so the result is always correct (see [`is_prime_u64`]).
+    pub fn is_prime(&self) -> bool {
+        is_prime_u64(self.0)
+    }
+
+    /// Factors this Natural into its prime factors. See [`factor`] for details.
+    pub fn factor(&self) -> Factorization<Natural> {
+        factor(*self)
+    }
+}
+
+impl From<u64> for Natural {
+    fn from(value: u64) -> Self {
+        Natural(value)
+    }
+}
+
+impl Display for Natural {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 impl Add for Natural {
     type Output = Natural;
     fn add(self, rhs: Self) -> Self::Output {
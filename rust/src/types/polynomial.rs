@@ -0,0 +1,806 @@
+mod div;
+mod mul;
+
+use crate::traits::*;
+use crate::util::inverse_mod;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// The variable of a [`Polynomial`], which is part of its type.
+///
+/// Polynomials in different variables are different types, so mixing them fails to compile:
+/// ```compile_fail
+/// use polydiv::types::{Polynomial, Rational, X, Y};
+///
+/// let p: Polynomial<Rational, X> = Polynomial::x();
+/// let q: Polynomial<Rational, Y> = Polynomial::x();
+/// let _ = p + q;
+/// ```
+///
+/// Further variables can be defined via unit structs:
+/// ```
+/// use polydiv::types::{Polynomial, Rational, Variable};
+///
+/// struct T;
+/// impl Variable for T {
+///     const NAME: &'static str = "t";
+/// }
+///
+/// let t: Polynomial<Rational, T> = Polynomial::x();
+/// assert_eq!(t.to_string(), "t");
+/// ```
+pub trait Variable {
+    /// The symbol used for displaying the variable.
+    const NAME: &'static str;
+}
+
+/// The variable `x`, which is the default variable of polynomials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct X;
+
+/// The variable `y`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Y;
+
+/// The variable `z`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Z;
+
+impl Variable for X {
+    const NAME: &'static str = "x";
+}
+
+impl Variable for Y {
+    const NAME: &'static str = "y";
+}
+
+impl Variable for Z {
+    const NAME: &'static str = "z";
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolynomialDivErr {
+    /// The divisor is the zero polynomial.
+    DivideByZero,
+    /// The leading coefficient of the divisor is not a unit, so the division with remainder isn't possible in general.
+    NonUnitLeadingCoefficient,
+    /// The divisor doesn't divide the dividend.
+    NotExact,
+}
+
+impl Display for PolynomialDivErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolynomialDivErr::DivideByZero => write!(f, "the divisor is the zero polynomial"),
+            PolynomialDivErr::NonUnitLeadingCoefficient => {
+                write!(f, "the leading coefficient of the divisor is not a unit")
+            }
+            PolynomialDivErr::NotExact => write!(f, "the divisor doesn't divide the dividend"),
+        }
+    }
+}
+
+impl std::error::Error for PolynomialDivErr {}
+
+/// A univariate [polynomial](https://en.wikipedia.org/wiki/Polynomial) `aₙxⁿ + ... + a₁x + a₀`
+/// with coefficients in the ring `R` and the [`Variable`] `V`.
+///
+/// The coefficients are stored densely, starting with the constant coefficient `a₀`.
+/// Polynomials are always normalized, i.e. the leading coefficient is non-zero, so that equal polynomials have equal coefficients.
+/// The zero polynomial has no coefficients at all.
+///
+/// Polynomials over a ring form a ring themselves, so they can be nested (e.g. `Polynomial<Polynomial<Rational, Y>>`
+/// for bivariate polynomials in `x` and `y`). If the coefficients form a field, polynomials are a euclidean domain with
+/// the degree as euclidean function. For non-commutative coefficients (e.g. [`Quaternion`](super::Quaternion)) the variable
+/// commutes with all coefficients, and the division with remainder distinguishes between dividing from the left and the right.
+pub struct Polynomial<R, V = X> {
+    coefs: Vec<R>,
+    variable: PhantomData<V>,
+}
+
+impl<R: Ring, V: Variable> Polynomial<R, V> {
+    /// Creates the zero polynomial.
+    pub fn new() -> Self {
+        Polynomial::from_vec(Vec::new())
+    }
+
+    /// Creates the zero polynomial, with space for `capacity` coefficients.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Polynomial::from_vec(Vec::with_capacity(capacity))
+    }
+
+    /// Creates a polynomial from its coefficients `[a₀, a₁, ..., aₙ]`, starting with the constant coefficient.
+    pub fn from_vec(coefs: Vec<R>) -> Self {
+        let mut p = Polynomial {
+            coefs,
+            variable: PhantomData,
+        };
+        p.normalize();
+        p
+    }
+
+    /// Creates the monomial `coef * x^pow`.
+    pub fn monomial(coef: R, pow: usize) -> Self {
+        let mut coefs = vec![R::zero(); pow];
+        coefs.push(coef);
+        Polynomial::from_vec(coefs)
+    }
+
+    /// Returns the polynomial `x`, i.e. the variable `V` itself.
+    pub fn x() -> Self {
+        Polynomial::monomial(R::one(), 1)
+    }
+
+    /// Returns the same polynomial in another variable.
+    pub fn with_variable<W: Variable>(self) -> Polynomial<R, W> {
+        Polynomial {
+            coefs: self.coefs,
+            variable: PhantomData,
+        }
+    }
+
+    /// Returns the coefficients `[a₀, a₁, ..., aₙ]`, starting with the constant coefficient.
+    pub fn coefs(&self) -> &Vec<R> {
+        &self.coefs
+    }
+
+    pub fn into_coefs(self) -> Vec<R> {
+        self.coefs
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefs.len().checked_sub(1)
+    }
+
+    /// Returns the number of coefficients, i.e. the degree plus one.
+    pub fn len(&self) -> usize {
+        self.coefs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.coefs.is_empty()
+    }
+
+    /// Returns the coefficient of `x^pow`, if it is stored.
+    pub fn get(&self, pow: usize) -> Option<&R> {
+        self.coefs.get(pow)
+    }
+
+    /// Returns the coefficient of `x^pow`, which is zero for all powers above the degree.
+    pub fn coef(&self, pow: usize) -> R {
+        self.coefs.get(pow).cloned().unwrap_or_else(R::zero)
+    }
+
+    /// Sets the coefficient of `x^pow`.
+    pub fn set_coef(&mut self, pow: usize, coef: R) {
+        if pow >= self.coefs.len() {
+            if coef.is_zero() {
+                return;
+            }
+            self.coefs.resize(pow + 1, R::zero());
+        }
+        self.coefs[pow] = coef;
+        self.normalize();
+    }
+
+    /// Returns the coefficient of the highest power, or `None` for the zero polynomial.
+    pub fn leading_coef(&self) -> Option<&R> {
+        self.coefs.last()
+    }
+
+    pub fn is_monic(&self) -> bool {
+        self.leading_coef().is_some_and(|c| c.is_one())
+    }
+
+    /// Iterates over the non-zero terms as pairs `(pow, coef)` in ascending order of their powers.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &R)> {
+        self.coefs.iter().enumerate().filter(|(_, c)| !c.is_zero())
+    }
+
+    /// Removes the zero coefficients of the highest powers.
+    fn normalize(&mut self) -> &mut Self {
+        while self.coefs.last().is_some_and(|c| c.is_zero()) {
+            self.coefs.pop();
+        }
+        self
+    }
+
+    /// Multiplies this polynomial with `x^amount`.
+    pub fn shl(&mut self, amount: usize) -> &mut Self {
+        if !self.is_zero() {
+            self.coefs.splice(0..0, vec![R::zero(); amount]);
+        }
+        self
+    }
+
+    /// Divides this polynomial by `x^amount`, dropping all terms of smaller powers.
+    pub fn shr(&mut self, amount: usize) -> &mut Self {
+        self.coefs.drain(0..amount.min(self.coefs.len()));
+        self
+    }
+
+    /// Multiplies this polynomial with the monomial `coef * x^pow`, where the coefficient is multiplied from the right.
+    pub fn simple_mul_mut(&mut self, coef: &R, pow: usize) -> &mut Self {
+        if !coef.is_one() {
+            self.coefs.iter_mut().for_each(|c| *c *= coef.clone());
+            self.normalize();
+        }
+        self.shl(pow)
+    }
+
+    /// Returns the product with the monomial `coef * x^pow`, see [`Polynomial::simple_mul_mut`].
+    pub fn simple_mul(&self, coef: &R, pow: usize) -> Self {
+        let mut p = self.clone();
+        p.simple_mul_mut(coef, pow);
+        p
+    }
+
+    /// Returns `self * self`.
+    ///
+    /// Squaring only needs to evaluate one operand in the recursive multiplication algorithms,
+    /// which is also used automatically when multiplying a polynomial with itself (e.g. `&p * &p`).
+    pub fn square(&self) -> Self {
+        Polynomial::from_vec(mul::mul(&self.coefs, None))
+    }
+
+    /// Computes `self^exp` via repeated squaring.
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = Polynomial::one();
+        for bit in (0..u64::BITS - exp.leading_zeros()).rev() {
+            result = result.square();
+            if (exp >> bit) & 1 == 1 {
+                result *= self;
+            }
+        }
+        result
+    }
+
+    /// Evaluates the polynomial at `x` via [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+    pub fn eval(&self, x: &R) -> R {
+        self.coefs
+            .iter()
+            .rev()
+            .fold(R::zero(), |acc, c| acc * x.clone() + c.clone())
+    }
+
+    /// Returns the formal [derivative](https://en.wikipedia.org/wiki/Formal_derivative).
+    pub fn derivative(&self) -> Self {
+        let coefs = self
+            .coefs
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, c)| mul_by_usize(c, i))
+            .collect();
+        Polynomial::from_vec(coefs)
+    }
+}
+
+impl<R: Ring, V: Variable> Polynomial<R, V> {
+    /// Divides with remainder from the right like [`Polynomial::right_div_rem`], which is possible over any ring,
+    /// as long as the leading coefficient of `rhs` is a unit (e.g. for monic divisors).
+    pub fn try_div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialDivErr> {
+        let lead_inverse = rhs
+            .leading_coef()
+            .ok_or(PolynomialDivErr::DivideByZero)?
+            .inverse()
+            .ok_or(PolynomialDivErr::NonUnitLeadingCoefficient)?;
+        self.long_division(rhs, |lead, _| Ok(lead.clone() * lead_inverse.clone()), true)
+    }
+
+    /// Polynomial long division, where `quotient` computes the next coefficient of the quotient from the leading
+    /// coefficients of the remainder and the divisor. The quotient is multiplied from the left, if `right` is set.
+    fn long_division(
+        &self,
+        rhs: &Self,
+        quotient: impl Fn(&R, &R) -> Result<R, PolynomialDivErr>,
+        right: bool,
+    ) -> Result<(Self, Self), PolynomialDivErr> {
+        let divisor_lead = rhs.leading_coef().ok_or(PolynomialDivErr::DivideByZero)?;
+        let divisor_degree = rhs.coefs.len() - 1;
+        if self.coefs.len() <= divisor_degree {
+            return Ok((Polynomial::new(), self.clone()));
+        }
+
+        let mut rem = self.coefs.clone();
+        let mut quot = vec![R::zero(); rem.len() - divisor_degree];
+        for shift in (0..quot.len()).rev() {
+            let lead = &rem[shift + divisor_degree];
+            if lead.is_zero() {
+                continue;
+            }
+            let c = quotient(lead, divisor_lead)?;
+            for (i, d) in rhs.coefs.iter().enumerate() {
+                let product = if right {
+                    c.clone() * d.clone()
+                } else {
+                    d.clone() * c.clone()
+                };
+                rem[shift + i] -= product;
+            }
+            quot[shift] = c;
+        }
+        rem.truncate(divisor_degree);
+        Ok((Polynomial::from_vec(quot), Polynomial::from_vec(rem)))
+    }
+}
+
+impl<R: CommutativeRing, V: Variable> Polynomial<R, V> {
+    /// [Pseudo-division](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor#Pseudo-remainder_sequences),
+    /// i.e. returns `(q, r)` with `lc(rhs)ᵏ * self = q * rhs + r` and `deg(r) < deg(rhs)`, where `k = max(deg(self) - deg(rhs) + 1, 0)`.
+    ///
+    /// Unlike the division with remainder, this never divides any coefficients, so it works over every commutative ring.
+    pub fn pseudo_div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialDivErr> {
+        let divisor_lead = rhs.leading_coef().ok_or(PolynomialDivErr::DivideByZero)?;
+        let divisor_degree = rhs.coefs.len() - 1;
+        if self.coefs.len() <= divisor_degree {
+            return Ok((Polynomial::new(), self.clone()));
+        }
+
+        // In each step: q ← lc·q + lead·xˢ and r ← lc·r - lead·xˢ·rhs
+        let mut rem = self.coefs.clone();
+        let mut quot = vec![R::zero(); rem.len() - divisor_degree];
+        for shift in (0..quot.len()).rev() {
+            let lead = rem[shift + divisor_degree].clone();
+            for c in quot[shift + 1..]
+                .iter_mut()
+                .chain(&mut rem[..shift + divisor_degree])
+            {
+                *c *= divisor_lead.clone();
+            }
+            for (i, d) in rhs.coefs[..divisor_degree].iter().enumerate() {
+                rem[shift + i] -= lead.clone() * d.clone();
+            }
+            quot[shift] = lead;
+        }
+        rem.truncate(divisor_degree);
+        Ok((Polynomial::from_vec(quot), Polynomial::from_vec(rem)))
+    }
+}
+
+impl<R: EuclideanDomain, V: Variable> Polynomial<R, V> {
+    /// Returns the quotient `self / rhs`, if `rhs` divides `self` (e.g. `(2x² - 2) / (2x + 2) = x - 1` over the Integers).
+    ///
+    /// Fails with [`PolynomialDivErr::NotExact`] otherwise, instead of truncating any coefficients.
+    pub fn exact_div(&self, rhs: &Self) -> Result<Self, PolynomialDivErr> {
+        let (quot, rem) = self.long_division(
+            rhs,
+            |lead, divisor_lead| match lead.div_rem(divisor_lead) {
+                (q, r) if r.is_zero() => Ok(q),
+                _ => Err(PolynomialDivErr::NotExact),
+            },
+            true,
+        )?;
+        if rem.is_zero() {
+            Ok(quot)
+        } else {
+            Err(PolynomialDivErr::NotExact)
+        }
+    }
+}
+
+impl<R: DivisionRing, V: Variable> Polynomial<R, V> {
+    /// Divides with remainder from the right, i.e. returns `(q, r)` with `self = q * rhs + r` and `deg(r) < deg(rhs)`.
+    ///
+    /// For commutative coefficients this is the same as [`Polynomial::left_div_rem`] and [`EuclideanDomain::div_rem`].
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn right_div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.long_division(
+            rhs,
+            |lead, divisor_lead| Ok(R::divide(lead, divisor_lead)),
+            true,
+        )
+        .unwrap_or_else(|_| panic!("Divide by zero error"))
+    }
+
+    /// Divides with remainder from the left, i.e. returns `(q, r)` with `self = rhs * q + r` and `deg(r) < deg(rhs)`.
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn left_div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.long_division(
+            rhs,
+            |lead, divisor_lead| Ok(R::left_divide(lead, divisor_lead)),
+            false,
+        )
+        .unwrap_or_else(|_| panic!("Divide by zero error"))
+    }
+
+    /// Scales this polynomial, such that it is monic. The zero polynomial stays zero.
+    pub fn monic(&self) -> Self {
+        match self.leading_coef() {
+            Some(lead) => self.simple_mul(&lead.multiplicative_inverse(), 0),
+            None => self.clone(),
+        }
+    }
+}
+
+impl<F: Field, V: Variable> Polynomial<F, V> {
+    /// Returns the first `precision` coefficients of the [power series](https://en.wikipedia.org/wiki/Formal_power_series)
+    /// `1 / self`, computed via Newton iteration.
+    ///
+    /// Panics if the constant coefficient is zero.
+    pub fn inverse_series(&self, precision: usize) -> Self {
+        if precision == 0 {
+            return Polynomial::new();
+        }
+        Polynomial::from_vec(div::series_inverse(&self.coefs, precision))
+    }
+
+    /// Returns the inverse of this polynomial in the quotient ring *F\[x\]/(modulus)*, if it is coprime to `modulus`.
+    ///
+    /// The inverse has a smaller degree than `modulus`. See [`inverse_mod`].
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        inverse_mod(self.clone(), modulus.clone())
+    }
+}
+
+// Implemented manually, so that the variable marker doesn't need to implement these traits
+
+impl<R: Clone, V> Clone for Polynomial<R, V> {
+    fn clone(&self) -> Self {
+        Polynomial {
+            coefs: self.coefs.clone(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: PartialEq, V> PartialEq for Polynomial<R, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.coefs == other.coefs
+    }
+}
+
+impl<R: Eq, V> Eq for Polynomial<R, V> {}
+
+impl<R: Hash, V> Hash for Polynomial<R, V> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.coefs.hash(state);
+    }
+}
+
+impl<R: Debug, V: Variable> Debug for Polynomial<R, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Polynomial")
+            .field("coefs", &self.coefs)
+            .field("variable", &V::NAME)
+            .finish()
+    }
+}
+
+impl<R: Ring, V: Variable> Default for Polynomial<R, V> {
+    fn default() -> Self {
+        Polynomial::new()
+    }
+}
+
+impl<R: Ring, V: Variable> From<R> for Polynomial<R, V> {
+    fn from(value: R) -> Self {
+        Polynomial::from_vec(vec![value])
+    }
+}
+
+/// Shows the terms in descending order, e.g. `3x^2 + 2x + 1`.
+///
+/// Negative coefficients are shown as `+ -n`, as checking whether a coefficient is negative would
+/// require the ring to be ordered. Coefficients, which aren't plain numbers (e.g. polynomials), are put in parentheses.
+impl<R: Ring + Display, V: Variable> Display for Polynomial<R, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", R::zero());
+        }
+        let terms: Vec<String> = self
+            .terms()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .map(|(pow, coef)| match pow {
+                0 => format_term(coef, None),
+                1 => format_term(coef, Some(V::NAME)),
+                _ => format_term(coef, Some(&format!("{}^{pow}", V::NAME))),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+/// Formats the term `coef * power`, where `power` is the variable part, e.g. `x^2`.
+/// Constant terms have no variable part.
+pub(super) fn format_term<R: Ring + Display>(coef: &R, power: Option<&str>) -> String {
+    let coef_str = coef.to_string();
+    let is_number = coef_str
+        .chars()
+        .all(|c| c.is_ascii_digit() || c == '-' || c == '/');
+    let coef_str = if is_number {
+        coef_str
+    } else {
+        format!("({coef_str})")
+    };
+    match power {
+        None => coef_str,
+        Some(power) if coef.is_one() => power.to_string(),
+        Some(power) => format!("{coef_str}{power}"),
+    }
+}
+
+/// Computes the `n`-fold sum `c + ... + c` by doubling, so that only `O(log n)` additions are needed.
+pub(super) fn mul_by_usize<R: Ring>(c: &R, n: usize) -> R {
+    let mut result = R::zero();
+    for bit in (0..usize::BITS - n.leading_zeros()).rev() {
+        result = result.clone() + result;
+        if (n >> bit) & 1 == 1 {
+            result += c.clone();
+        }
+    }
+    result
+}
+
+// The arithmetic is implemented on references, so that neither the polynomials nor their coefficients need to be `Copy`.
+// The by-value operators, which are required by the ring traits, reuse the buffer of their left operand.
+
+impl<R: Ring, V: Variable> AddAssign<&Polynomial<R, V>> for Polynomial<R, V> {
+    fn add_assign(&mut self, rhs: &Polynomial<R, V>) {
+        if self.coefs.len() < rhs.coefs.len() {
+            self.coefs.resize(rhs.coefs.len(), R::zero());
+        }
+        for (c1, c2) in self.coefs.iter_mut().zip(&rhs.coefs) {
+            *c1 += c2.clone();
+        }
+        self.normalize();
+    }
+}
+
+impl<R: Ring, V: Variable> AddAssign for Polynomial<R, V> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Add<&Polynomial<R, V>> for &Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn add(self, rhs: &Polynomial<R, V>) -> Self::Output {
+        let mut p = self.clone();
+        p += rhs;
+        p
+    }
+}
+
+impl<R: Ring, V: Variable> Add<&Polynomial<R, V>> for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn add(mut self, rhs: &Polynomial<R, V>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> Add for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> SubAssign<&Polynomial<R, V>> for Polynomial<R, V> {
+    fn sub_assign(&mut self, rhs: &Polynomial<R, V>) {
+        if self.coefs.len() < rhs.coefs.len() {
+            self.coefs.resize(rhs.coefs.len(), R::zero());
+        }
+        for (c1, c2) in self.coefs.iter_mut().zip(&rhs.coefs) {
+            *c1 -= c2.clone();
+        }
+        self.normalize();
+    }
+}
+
+impl<R: Ring, V: Variable> SubAssign for Polynomial<R, V> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Sub<&Polynomial<R, V>> for &Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn sub(self, rhs: &Polynomial<R, V>) -> Self::Output {
+        let mut p = self.clone();
+        p -= rhs;
+        p
+    }
+}
+
+impl<R: Ring, V: Variable> Sub<&Polynomial<R, V>> for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn sub(mut self, rhs: &Polynomial<R, V>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> Sub for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> Neg for &Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coefs: self.coefs.iter().map(|c| -c.clone()).collect(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: Ring, V: Variable> Neg for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn neg(self) -> Self::Output {
+        Polynomial {
+            coefs: self.coefs.into_iter().map(|c| -c).collect(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: Ring, V: Variable> Mul<&Polynomial<R, V>> for &Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn mul(self, rhs: &Polynomial<R, V>) -> Self::Output {
+        if std::ptr::eq(self, rhs) {
+            return self.square();
+        }
+        // The leading coefficients might be zero divisors
+        let coefs = mul::mul(&self.coefs, Some(&rhs.coefs));
+        Polynomial::from_vec(coefs)
+    }
+}
+
+impl<R: Ring, V: Variable> Mul<&Polynomial<R, V>> for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn mul(self, rhs: &Polynomial<R, V>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<R: Ring, V: Variable> Mul for Polynomial<R, V> {
+    type Output = Polynomial<R, V>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<R: Ring, V: Variable> MulAssign<&Polynomial<R, V>> for Polynomial<R, V> {
+    fn mul_assign(&mut self, rhs: &Polynomial<R, V>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> MulAssign for Polynomial<R, V> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+/// The quotient of the euclidean division, see [`EuclideanDomain::div_rem`].
+impl<F: Field, V: Variable> Div<&Polynomial<F, V>> for &Polynomial<F, V> {
+    type Output = Polynomial<F, V>;
+    fn div(self, rhs: &Polynomial<F, V>) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<F: Field, V: Variable> Div for Polynomial<F, V> {
+    type Output = Polynomial<F, V>;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<F: Field, V: Variable> DivAssign<&Polynomial<F, V>> for Polynomial<F, V> {
+    fn div_assign(&mut self, rhs: &Polynomial<F, V>) {
+        *self = self.div_rem(rhs).0;
+    }
+}
+
+impl<F: Field, V: Variable> DivAssign for Polynomial<F, V> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).0;
+    }
+}
+
+/// The remainder of the euclidean division, see [`EuclideanDomain::div_rem`].
+impl<F: Field, V: Variable> Rem<&Polynomial<F, V>> for &Polynomial<F, V> {
+    type Output = Polynomial<F, V>;
+    fn rem(self, rhs: &Polynomial<F, V>) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<F: Field, V: Variable> Rem for Polynomial<F, V> {
+    type Output = Polynomial<F, V>;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<F: Field, V: Variable> RemAssign<&Polynomial<F, V>> for Polynomial<F, V> {
+    fn rem_assign(&mut self, rhs: &Polynomial<F, V>) {
+        *self = self.div_rem(rhs).1;
+    }
+}
+
+impl<F: Field, V: Variable> RemAssign for Polynomial<F, V> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).1;
+    }
+}
+
+impl<R: Ring, V: Variable> Set<Polynomial<R, V>> for Polynomial<R, V> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<R: Ring, V: Variable> Associative for Polynomial<R, V> {}
+impl<R: Ring + Commutative, V: Variable> Commutative for Polynomial<R, V> {}
+impl<R: Ring, V: Variable> LeftDistributive for Polynomial<R, V> {}
+impl<R: Ring, V: Variable> RightDistributive for Polynomial<R, V> {}
+
+impl<R: Ring, V: Variable> NearRing for Polynomial<R, V> {
+    fn zero() -> Self {
+        Polynomial::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.coefs.is_empty()
+    }
+}
+
+impl<R: Ring, V: Variable> Ring for Polynomial<R, V> {
+    fn one() -> Self {
+        Polynomial::from(R::one())
+    }
+
+    /// Returns the inverse of constant polynomials with a unit coefficient.
+    ///
+    /// Over an integral domain these are all units; if the coefficients contain nilpotent elements
+    /// (e.g. *ℤ/4ℤ*, where `(1 + 2x)² = 1`), other units are not detected.
+    fn inverse(&self) -> Option<Self> {
+        match self.coefs.as_slice() {
+            [c] => c.inverse().map(Polynomial::from),
+            _ => None,
+        }
+    }
+}
+
+impl<F: Field, V: Variable> EuclideanDomain for Polynomial<F, V> {
+    /// Uses long division for small degrees and [Newton iteration](Polynomial::inverse_series) for large ones.
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        let quot_len = (self.coefs.len() + 1).saturating_sub(rhs.coefs.len());
+        if quot_len.min(rhs.coefs.len()) < div::NEWTON_THRESHOLD {
+            return self.right_div_rem(rhs);
+        }
+        let (quot, rem) = div::div_rem(&self.coefs, &rhs.coefs);
+        (Polynomial::from_vec(quot), Polynomial::from_vec(rem))
+    }
+
+    /// The degree plus one, so that the zero polynomial has the smallest value.
+    fn euclidean_value(&self) -> u128 {
+        self.coefs.len() as u128
+    }
+
+    /// The inverse of the leading coefficient, so that the unit normal form is monic.
+    fn normalizing_unit(&self) -> Self {
+        match self.leading_coef() {
+            Some(lead) => Polynomial::from(lead.multiplicative_inverse()),
+            None => Polynomial::one(),
+        }
+    }
+}
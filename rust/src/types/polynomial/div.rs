@@ -0,0 +1,60 @@
+//! Fast division of dense coefficient slices via [Newton iteration](https://en.wikipedia.org/wiki/Newton%27s_method).
+//!
+//! Reversing the coefficients turns the division `a = q·b + r` into a congruence of power series
+//! `rev(a) ≡ rev(q)·rev(b) (mod xⁿ⁻ᵐ⁺¹)`, so the quotient is a truncated product with the power series inverse of `rev(b)`.
+//! The inverse is computed with Newton's method `g ← g·(2 - f·g)`, which doubles the precision in each step.
+//! Thus the division costs a constant number of multiplications.
+
+use super::mul::mul;
+use crate::traits::*;
+
+/// From this length of both the quotient and the divisor on, the division uses Newton iteration instead of long division.
+/// Measured with `ZMod`, where the crossover coincides with the NTT taking over the multiplications.
+pub(super) const NEWTON_THRESHOLD: usize = 128;
+
+/// Returns the first `precision` coefficients of the power series inverse of `f`.
+///
+/// Panics if the constant coefficient of `f` is zero.
+pub(super) fn series_inverse<F: Field>(f: &[F], precision: usize) -> Vec<F> {
+    let constant = f.first().filter(|c| !c.is_zero());
+    let mut g = vec![constant
+        .expect("The constant coefficient has to be invertible")
+        .multiplicative_inverse()];
+    let mut len = 1;
+    while len < precision {
+        len = (2 * len).min(precision);
+        // g·(2 - f·g) = g - g·(f·g - 1), where the first coefficients of f·g - 1 are zero
+        let mut error = mul(&f[..len.min(f.len())], Some(&g));
+        error.resize(len, F::zero());
+        error[0] -= F::one();
+        let mut correction = mul(&g, Some(&error));
+        correction.resize(len, F::zero());
+        g.resize(len, F::zero());
+        for (x, c) in g.iter_mut().zip(correction) {
+            *x -= c;
+        }
+    }
+    g.truncate(precision);
+    g
+}
+
+/// Divides `a` by `b` with remainder, where `b` has no trailing zeros and `deg(a) ≥ deg(b)`.
+pub(super) fn div_rem<F: Field>(a: &[F], b: &[F]) -> (Vec<F>, Vec<F>) {
+    let quot_len = a.len() - b.len() + 1;
+    let rev_a: Vec<F> = a.iter().rev().take(quot_len).cloned().collect();
+    let rev_b: Vec<F> = b.iter().rev().cloned().collect();
+
+    let mut quot = mul(&rev_a, Some(&series_inverse(&rev_b, quot_len)));
+    quot.resize(quot_len, F::zero());
+    quot.reverse();
+
+    // The remainder has a lower degree than b, so only the lowest coefficients of q·b are needed
+    let rem_len = b.len() - 1;
+    let product = mul(&quot[..quot_len.min(rem_len)], Some(&b[..rem_len]));
+    let rem = a[..rem_len]
+        .iter()
+        .zip(product.into_iter().chain(std::iter::repeat_with(F::zero)))
+        .map(|(x, y)| x.clone() - y)
+        .collect();
+    (quot, rem)
+}
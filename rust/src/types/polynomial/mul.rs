@@ -0,0 +1,194 @@
+//! Multiplication of dense coefficient slices.
+//!
+//! Coefficient types with a faster multiplication (e.g. via NTT or FFT) provide it via [`Ring::convolve`],
+//! which is tried first. Otherwise one of the generic algorithms below is used.
+//! All generic algorithms accumulate into a preallocated output buffer of length `a.len() + b.len() - 1`.
+//! They only use the ring operations of the coefficients and the central scalars `2` and `3`,
+//! so they work for non-commutative coefficients as well.
+
+use crate::traits::*;
+
+// The thresholds were measured with word-sized coefficients (`ZMod`); for coefficients with more expensive
+// multiplications (e.g. Rationals or nested polynomials) the fast algorithms pay off even earlier.
+
+/// Below this length of the shorter operand, schoolbook multiplication is used.
+const KARATSUBA_THRESHOLD: usize = 24;
+/// From this length of the shorter operand on, Toom-3 is used instead of Karatsuba (if 2 and 3 are units).
+const TOOM3_THRESHOLD: usize = 96;
+
+/// Returns `a * b`, or `a²` if `b` is `None`.
+pub(super) fn mul<R: Ring>(a: &[R], b: Option<&[R]>) -> Vec<R> {
+    let b_len = b.map_or(a.len(), |b| b.len());
+    if a.is_empty() || b_len == 0 {
+        return Vec::new();
+    }
+    if let Some(product) = R::convolve(a, b.unwrap_or(a)) {
+        return product;
+    }
+    let mut out = vec![R::zero(); a.len() + b_len - 1];
+    let two = R::one() + R::one();
+    let toom3_inverses = two.inverse().zip((two + R::one()).inverse());
+    mul_into(a, b, &mut out, &toom3_inverses);
+    out
+}
+
+/// Adds `a * b` (or `a²` if `b` is `None`) to `out`, choosing the algorithm based on the operands' lengths.
+fn mul_into<R: Ring>(a: &[R], b: Option<&[R]>, out: &mut [R], inverses: &Option<(R, R)>) {
+    let b_len = b.map_or(a.len(), |b| b.len());
+    let min_len = a.len().min(b_len);
+    if min_len == 0 {
+        return;
+    }
+    if min_len < KARATSUBA_THRESHOLD {
+        return schoolbook(a, b.unwrap_or(a), out);
+    }
+
+    if let Some(b) = b {
+        // Split the longer operand into chunks of the shorter one's length, so that the recursion stays balanced
+        if a.len() >= 2 * b.len() {
+            for (i, chunk) in a.chunks(b.len()).enumerate() {
+                mul_into(chunk, Some(b), &mut out[i * b.len()..], inverses);
+            }
+            return;
+        }
+        if b.len() >= 2 * a.len() {
+            for (i, chunk) in b.chunks(a.len()).enumerate() {
+                mul_into(a, Some(chunk), &mut out[i * a.len()..], inverses);
+            }
+            return;
+        }
+    }
+
+    match inverses {
+        Some((inv2, inv3)) if min_len >= TOOM3_THRESHOLD => toom3(a, b, out, inv2, inv3, inverses),
+        _ => karatsuba(a, b, out, inverses),
+    }
+}
+
+/// Adds the schoolbook product `a * b` to `out`, without allocating any intermediate polynomials.
+fn schoolbook<R: Ring>(a: &[R], b: &[R], out: &mut [R]) {
+    for (i, x) in a.iter().enumerate() {
+        if x.is_zero() {
+            continue;
+        }
+        for (o, y) in out[i..].iter_mut().zip(b) {
+            *o += x.clone() * y.clone();
+        }
+    }
+}
+
+/// [Karatsuba multiplication](https://en.wikipedia.org/wiki/Karatsuba_algorithm) with
+/// `(a₀ + a₁xᵐ)(b₀ + b₁xᵐ) = a₀b₀ + ((a₀ + a₁)(b₀ + b₁) - a₀b₀ - a₁b₁)xᵐ + a₁b₁x²ᵐ`.
+fn karatsuba<R: Ring>(a: &[R], b: Option<&[R]>, out: &mut [R], inverses: &Option<(R, R)>) {
+    let b_len = b.map_or(a.len(), |b| b.len());
+    let m = a.len().max(b_len).div_ceil(2);
+    let (a0, a1) = a.split_at(m.min(a.len()));
+    let b_parts = b.map(|b| b.split_at(m.min(b.len())));
+
+    let z0 = product(a0, b_parts.map(|(b0, _)| b0), inverses);
+    let z2 = product(a1, b_parts.map(|(_, b1)| b1), inverses);
+    let a_sum = add(a0, a1);
+    let b_sum = b_parts.map(|(b0, b1)| add(b0, b1));
+    let mut z1 = product(&a_sum, b_sum.as_deref(), inverses);
+    sub_assign(&mut z1, &z0);
+    sub_assign(&mut z1, &z2);
+
+    add_assign(out, &z0);
+    add_assign(&mut out[m..], &z1);
+    if !z2.is_empty() {
+        add_assign(&mut out[2 * m..], &z2);
+    }
+}
+
+/// [Toom-3 multiplication](https://en.wikipedia.org/wiki/Toom%E2%80%93Cook_multiplication), which splits the operands
+/// into three parts, evaluates them at `0, 1, -1, -2, ∞` and interpolates the product with Bodrato's sequence.
+fn toom3<R: Ring>(
+    a: &[R],
+    b: Option<&[R]>,
+    out: &mut [R],
+    inv2: &R,
+    inv3: &R,
+    inverses: &Option<(R, R)>,
+) {
+    let b_len = b.map_or(a.len(), |b| b.len());
+    let k = a.len().max(b_len).div_ceil(3);
+    let a_points = evaluate(a, k);
+    let b_points = b.map(|b| evaluate(b, k));
+    let [r0, r1, rm1, rm2, rinf] = std::array::from_fn(|i| {
+        let mut r = product(
+            &a_points[i],
+            b_points.as_ref().map(|p| p[i].as_slice()),
+            inverses,
+        );
+        r.resize(2 * k - 1, R::zero());
+        r
+    });
+
+    let scale = |v: Vec<R>, s: &R| -> Vec<R> { v.into_iter().map(|c| c * s.clone()).collect() };
+    let mut t3 = scale(sub(&rm2, &r1), inv3);
+    let t1 = scale(sub(&r1, &rm1), inv2);
+    let mut t2 = sub(&rm1, &r0);
+    t3 = add(&scale(sub(&t2, &t3), inv2), &add(&rinf, &rinf));
+    t2 = sub(&add(&t2, &t1), &rinf);
+    let t1 = sub(&t1, &t3);
+
+    for (i, r) in [r0, t1, t2, t3, rinf].iter().enumerate() {
+        if i * k < out.len() {
+            add_assign(&mut out[i * k..], r);
+        }
+    }
+}
+
+/// Evaluates `a₀ + a₁y + a₂y²` with `aᵢ = a[ik..(i+1)k]` at `y = 0, 1, -1, -2, ∞`.
+fn evaluate<R: Ring>(a: &[R], k: usize) -> [Vec<R>; 5] {
+    let part = |i: usize| &a[(i * k).min(a.len())..((i + 1) * k).min(a.len())];
+    let (a0, a1, a2) = (part(0), part(1), part(2));
+    let even = add(a0, a2);
+    let p1 = add(&even, a1);
+    let pm1 = sub(&even, a1);
+    // p(-2) = 2(p(-1) + a₂) - a₀
+    let pm1_a2 = add(&pm1, a2);
+    let pm2 = sub(&add(&pm1_a2, &pm1_a2), a0);
+    [a0.to_vec(), p1, pm1, pm2, a2.to_vec()]
+}
+
+fn product<R: Ring>(a: &[R], b: Option<&[R]>, inverses: &Option<(R, R)>) -> Vec<R> {
+    let b_len = b.map_or(a.len(), |b| b.len());
+    if a.is_empty() || b_len == 0 {
+        return Vec::new();
+    }
+    let mut out = vec![R::zero(); a.len() + b_len - 1];
+    mul_into(a, b, &mut out, inverses);
+    out
+}
+
+fn add<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut sum = a.to_vec();
+    if sum.len() < b.len() {
+        sum.resize(b.len(), R::zero());
+    }
+    add_assign(&mut sum, b);
+    sum
+}
+
+fn sub<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut diff = a.to_vec();
+    if diff.len() < b.len() {
+        diff.resize(b.len(), R::zero());
+    }
+    sub_assign(&mut diff, b);
+    diff
+}
+
+/// Adds `b` to `a`, ignoring the coefficients of `b` beyond `a`'s length (which are zero for all callers).
+fn add_assign<R: Ring>(a: &mut [R], b: &[R]) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x += y.clone();
+    }
+}
+
+fn sub_assign<R: Ring>(a: &mut [R], b: &[R]) {
+    for (x, y) in a.iter_mut().zip(b) {
+        *x -= y.clone();
+    }
+}
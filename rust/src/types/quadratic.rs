@@ -0,0 +1,520 @@
+use super::{Integer, Rational};
+use crate::traits::*;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An element `a + b√D` of the [quadratic field](https://en.wikipedia.org/wiki/Quadratic_field) *ℚ(√D)*.
+///
+/// `D` has to be a square-free integer other than `0` and `1`, which is checked at compile time.
+/// The field is real for `D > 0` and imaginary for `D < 0`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuadraticField<const D: i64> {
+    a: Rational,
+    b: Rational,
+}
+
+/// An element of the [ring of integers](https://en.wikipedia.org/wiki/Quadratic_integer) *𝒪ₖ* of *ℚ(√D)*.
+///
+/// The ring of integers is *ℤ\[ω\]*, where `ω = (1 + √D) / 2` if `D ≡ 1 (mod 4)` and `ω = √D` otherwise.
+/// Elements are stored as `a + bω` with integers `a` and `b`.
+///
+/// If *𝒪ₖ* is [norm-euclidean](https://en.wikipedia.org/wiki/Euclidean_domain#Norm-Euclidean_fields),
+/// it is a euclidean domain with the absolute value of the norm as euclidean function.
+/// This holds for `D ∈ {-11, -7, -3, -2, -1, 2, 3, 5, 6, 7, 11, 13, 17, 19, 21, 29, 33, 37, 41, 57, 73}`,
+/// which are marked via [`NormEuclidean`]; for any other `D` there is no [`EuclideanDomain`] implementation.
+///
+/// ```compile_fail
+/// use polydiv::traits::EuclideanDomain;
+/// use polydiv::types::QuadraticInteger;
+/// // ℤ[√-5] isn't even a unique factorization domain
+/// let x = QuadraticInteger::<-5>::from(6i64);
+/// let _ = x.div_rem(&x);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct QuadraticInteger<const D: i64> {
+    a: Integer,
+    b: Integer,
+}
+
+const fn is_squarefree(d: i64) -> bool {
+    let d = d.unsigned_abs();
+    let mut p = 2;
+    while p * p <= d {
+        if d.is_multiple_of(p * p) {
+            return false;
+        }
+        p += 1;
+    }
+    d != 0
+}
+
+/// Marks the norm-euclidean rings of integers `QuadraticInteger<D>`, for which the euclidean division is implemented.
+///
+/// By a theorem of Chatland and Davenport, these are exactly the ones listed in [`QuadraticInteger`].
+pub trait NormEuclidean {}
+
+macro_rules! norm_euclidean {
+    ($($d:literal),* $(,)?) => {
+        $(impl NormEuclidean for QuadraticInteger<$d> {})*
+    };
+}
+
+norm_euclidean!(-11, -7, -3, -2, -1, 2, 3, 5, 6, 7, 11, 13, 17, 19, 21, 29, 33, 37, 41, 57, 73);
+
+impl<const D: i64> QuadraticField<D> {
+    const VALID_D: () = assert!(
+        D != 1 && is_squarefree(D),
+        "D has to be a square-free integer other than 0 and 1"
+    );
+
+    pub fn new(a: Rational, b: Rational) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_D;
+        QuadraticField { a, b }
+    }
+
+    /// Returns `√D`.
+    pub fn sqrt_d() -> Self {
+        QuadraticField::new(Rational::zero(), Rational::one())
+    }
+
+    /// The rational part `a` of `a + b√D`.
+    pub fn a(&self) -> Rational {
+        self.a
+    }
+
+    /// The coefficient `b` of `√D` in `a + b√D`.
+    pub fn b(&self) -> Rational {
+        self.b
+    }
+
+    /// The conjugate `a - b√D`, i.e. the image under the non-trivial automorphism of the field.
+    pub fn conjugate(&self) -> Self {
+        QuadraticField::new(self.a, -self.b)
+    }
+
+    /// The [norm](https://en.wikipedia.org/wiki/Field_norm) `a² - Db²`, i.e. the product with the conjugate.
+    pub fn norm(&self) -> Rational {
+        self.a * self.a - Rational::from(D) * self.b * self.b
+    }
+
+    /// The [trace](https://en.wikipedia.org/wiki/Field_trace) `2a`, i.e. the sum with the conjugate.
+    pub fn trace(&self) -> Rational {
+        self.a + self.a
+    }
+
+    /// Checks whether this element lies in the ring of integers, i.e. whether norm and trace are integers.
+    pub fn is_integral(&self) -> bool {
+        self.norm().is_integer() && self.trace().is_integer()
+    }
+}
+
+impl<const D: i64> From<Rational> for QuadraticField<D> {
+    fn from(value: Rational) -> Self {
+        QuadraticField::new(value, Rational::zero())
+    }
+}
+
+impl<const D: i64> From<Integer> for QuadraticField<D> {
+    fn from(value: Integer) -> Self {
+        QuadraticField::from(Rational::from(value))
+    }
+}
+
+impl<const D: i64> From<i64> for QuadraticField<D> {
+    fn from(value: i64) -> Self {
+        QuadraticField::from(Rational::from(value))
+    }
+}
+
+impl<const D: i64> Display for QuadraticField<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let radical = match self.b.abs() {
+            b if b.is_one() => format!("√{D}"),
+            b => format!("{b}√{D}"),
+        };
+        if self.b.is_zero() {
+            write!(f, "{}", self.a)
+        } else if self.a.is_zero() {
+            let sign = if self.b.numer().is_negative() {
+                "-"
+            } else {
+                ""
+            };
+            write!(f, "{sign}{radical}")
+        } else if self.b.numer().is_negative() {
+            write!(f, "{} - {radical}", self.a)
+        } else {
+            write!(f, "{} + {radical}", self.a)
+        }
+    }
+}
+
+impl<const D: i64> Add for QuadraticField<D> {
+    type Output = QuadraticField<D>;
+    fn add(self, rhs: Self) -> Self::Output {
+        QuadraticField::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+impl<const D: i64> AddAssign for QuadraticField<D> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const D: i64> Sub for QuadraticField<D> {
+    type Output = QuadraticField<D>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        QuadraticField::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+impl<const D: i64> SubAssign for QuadraticField<D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const D: i64> Neg for QuadraticField<D> {
+    type Output = QuadraticField<D>;
+    fn neg(self) -> Self::Output {
+        QuadraticField::new(-self.a, -self.b)
+    }
+}
+
+impl<const D: i64> Mul for QuadraticField<D> {
+    type Output = QuadraticField<D>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        QuadraticField::new(
+            self.a * rhs.a + Rational::from(D) * self.b * rhs.b,
+            self.a * rhs.b + self.b * rhs.a,
+        )
+    }
+}
+
+impl<const D: i64> MulAssign for QuadraticField<D> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const D: i64> Div for QuadraticField<D> {
+    type Output = QuadraticField<D>;
+    fn div(self, rhs: Self) -> Self::Output {
+        DivisionRing::divide(&self, &rhs)
+    }
+}
+
+impl<const D: i64> DivAssign for QuadraticField<D> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl<const D: i64> Set<QuadraticField<D>> for QuadraticField<D> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<const D: i64> Associative for QuadraticField<D> {}
+impl<const D: i64> Commutative for QuadraticField<D> {}
+impl<const D: i64> LeftDistributive for QuadraticField<D> {}
+impl<const D: i64> RightDistributive for QuadraticField<D> {}
+
+/// `-1` is only a square in *ℚ(i)*.
+impl<const D: i64> MinusOneSquare for QuadraticField<D> {
+    const IS_SQUARE: bool = D == -1;
+}
+
+impl<const D: i64> NearRing for QuadraticField<D> {
+    fn zero() -> Self {
+        QuadraticField::from(Rational::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+}
+
+impl<const D: i64> Ring for QuadraticField<D> {
+    fn one() -> Self {
+        QuadraticField::from(Rational::one())
+    }
+
+    /// Computes the inverse as `conjugate / norm`, as the norm of a non-zero element is non-zero.
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.norm().inverse()?;
+        Some(QuadraticField::new(self.a * norm, -self.b * norm))
+    }
+}
+
+impl<const D: i64> DivisionRing for QuadraticField<D> {}
+
+impl<const D: i64> QuadraticInteger<D> {
+    /// `ω² = Tω + N`, i.e. `T` is the trace of `ω` and `-N` its norm.
+    const OMEGA_SQUARE: (i64, i64) = if D.rem_euclid(4) == 1 {
+        (1, (D - 1) / 4)
+    } else {
+        (0, D)
+    };
+
+    /// Creates `a + bω`.
+    pub fn new(a: Integer, b: Integer) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = QuadraticField::<D>::VALID_D;
+        QuadraticInteger { a, b }
+    }
+
+    /// Returns the generator `ω` of the ring of integers.
+    pub fn omega() -> Self {
+        QuadraticInteger::new(Integer::zero(), Integer::one())
+    }
+
+    /// The coefficient `a` of `a + bω`.
+    pub fn a(&self) -> Integer {
+        self.a
+    }
+
+    /// The coefficient `b` of `ω` in `a + bω`.
+    pub fn b(&self) -> Integer {
+        self.b
+    }
+
+    /// The conjugate, see [`QuadraticField::conjugate`].
+    pub fn conjugate(&self) -> Self {
+        // The conjugate of ω is T - ω
+        let t = Integer::from(Self::OMEGA_SQUARE.0);
+        QuadraticInteger::new(self.a + self.b * t, -self.b)
+    }
+
+    /// The norm `a² + Tab - Nb²`, see [`QuadraticField::norm`].
+    pub fn norm(&self) -> Integer {
+        let (t, n) = Self::OMEGA_SQUARE;
+        self.a * self.a + Integer::from(t) * self.a * self.b - Integer::from(n) * self.b * self.b
+    }
+
+    /// The trace `2a + Tb`, see [`QuadraticField::trace`].
+    pub fn trace(&self) -> Integer {
+        self.a + self.a + Integer::from(Self::OMEGA_SQUARE.0) * self.b
+    }
+}
+
+impl<const D: i64> From<QuadraticInteger<D>> for QuadraticField<D> {
+    fn from(value: QuadraticInteger<D>) -> Self {
+        let b = Rational::from(value.b);
+        if QuadraticInteger::<D>::OMEGA_SQUARE.0 == 1 {
+            // a + b(1 + √D) / 2
+            let half = Rational::new(Integer::one(), Integer::new(2));
+            QuadraticField::new(Rational::from(value.a) + b * half, b * half)
+        } else {
+            QuadraticField::new(Rational::from(value.a), b)
+        }
+    }
+}
+
+/// Fails with the original element, if it is not [integral](QuadraticField::is_integral).
+impl<const D: i64> TryFrom<QuadraticField<D>> for QuadraticInteger<D> {
+    type Error = QuadraticField<D>;
+    fn try_from(value: QuadraticField<D>) -> Result<Self, Self::Error> {
+        let (a, b) = if QuadraticInteger::<D>::OMEGA_SQUARE.0 == 1 {
+            (value.a - value.b, value.b + value.b)
+        } else {
+            (value.a, value.b)
+        };
+        if a.is_integer() && b.is_integer() {
+            Ok(QuadraticInteger::new(*a.numer(), *b.numer()))
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<const D: i64> From<Integer> for QuadraticInteger<D> {
+    fn from(value: Integer) -> Self {
+        QuadraticInteger::new(value, Integer::zero())
+    }
+}
+
+impl<const D: i64> From<u64> for QuadraticInteger<D> {
+    fn from(value: u64) -> Self {
+        QuadraticInteger::from(Integer::from(value))
+    }
+}
+
+impl<const D: i64> From<i64> for QuadraticInteger<D> {
+    fn from(value: i64) -> Self {
+        QuadraticInteger::from(Integer::from(value))
+    }
+}
+
+impl<const D: i64> Display for QuadraticInteger<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", QuadraticField::from(*self))
+    }
+}
+
+impl<const D: i64> Add for QuadraticInteger<D> {
+    type Output = QuadraticInteger<D>;
+    fn add(self, rhs: Self) -> Self::Output {
+        QuadraticInteger::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+impl<const D: i64> AddAssign for QuadraticInteger<D> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const D: i64> Sub for QuadraticInteger<D> {
+    type Output = QuadraticInteger<D>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        QuadraticInteger::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+impl<const D: i64> SubAssign for QuadraticInteger<D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const D: i64> Neg for QuadraticInteger<D> {
+    type Output = QuadraticInteger<D>;
+    fn neg(self) -> Self::Output {
+        QuadraticInteger::new(-self.a, -self.b)
+    }
+}
+
+impl<const D: i64> Mul for QuadraticInteger<D> {
+    type Output = QuadraticInteger<D>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // (a + bω)(c + eω) = ac + Nbe + (ae + bc + Tbe)ω
+        let (t, n) = Self::OMEGA_SQUARE;
+        let be = self.b * rhs.b;
+        QuadraticInteger::new(
+            self.a * rhs.a + Integer::from(n) * be,
+            self.a * rhs.b + self.b * rhs.a + Integer::from(t) * be,
+        )
+    }
+}
+
+impl<const D: i64> MulAssign for QuadraticInteger<D> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const D: i64> Set<QuadraticInteger<D>> for QuadraticInteger<D> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<const D: i64> Associative for QuadraticInteger<D> {}
+impl<const D: i64> Commutative for QuadraticInteger<D> {}
+impl<const D: i64> LeftDistributive for QuadraticInteger<D> {}
+impl<const D: i64> RightDistributive for QuadraticInteger<D> {}
+
+impl<const D: i64> NearRing for QuadraticInteger<D> {
+    fn zero() -> Self {
+        QuadraticInteger::from(Integer::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.a.is_zero() && self.b.is_zero()
+    }
+}
+
+impl<const D: i64> Ring for QuadraticInteger<D> {
+    fn one() -> Self {
+        QuadraticInteger::from(Integer::one())
+    }
+
+    /// The units are exactly the elements of norm `±1`.
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.norm();
+        if norm.is_unit() {
+            let conjugate = self.conjugate();
+            Some(QuadraticInteger::new(
+                conjugate.a * norm,
+                conjugate.b * norm,
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+impl<const D: i64> EuclideanDomain for QuadraticInteger<D>
+where
+    Self: NormEuclidean,
+{
+    /// Finds a quotient `q` with `|N(self / rhs - q)| < 1` close to the exact quotient in *ℚ(√D)*.
+    ///
+    /// For imaginary fields rounding both coordinates suffices, but as the norm of a real field is indefinite,
+    /// the best quotient might be further away. Therefore, the coordinate of `ω` is searched in a growing window
+    /// around the exact value; for each candidate the other coordinate is chosen close to the points
+    /// where the norm of the difference vanishes.
+    ///
+    /// Only available for norm-euclidean rings, see [`QuadraticInteger`].
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        if rhs.is_zero() {
+            panic!("Divide by zero error")
+        }
+        let exact = QuadraticField::from(*self) / QuadraticField::from(*rhs);
+        let quot = nearest_integer(&exact);
+        (quot, *self - quot * *rhs)
+    }
+
+    fn euclidean_value(&self) -> u128 {
+        self.norm().value().unsigned_abs()
+    }
+
+    /// Makes the first non-zero coordinate positive. For real fields there are infinitely many units,
+    /// so this does not choose a canonical representative for all associated elements.
+    fn normalizing_unit(&self) -> Self {
+        let sign = if self.a.is_zero() { self.b } else { self.a };
+        if sign.is_negative() {
+            -Self::one()
+        } else {
+            Self::one()
+        }
+    }
+}
+
+/// Finds `q ∈ 𝒪ₖ` with `|N(x - q)| < 1` for a norm-euclidean ring of integers.
+fn nearest_integer<const D: i64>(x: &QuadraticField<D>) -> QuadraticInteger<D> {
+    let t = QuadraticInteger::<D>::OMEGA_SQUARE.0;
+    // a + bω = (a + bT/2) + (b/s)√D
+    let s = if t == 1 { 2 } else { 1 };
+    let b_center = (x.b * Rational::from(s as i64)).round().value();
+
+    let mut best: Option<(Rational, QuadraticInteger<D>)> = None;
+    let mut window = 1;
+    loop {
+        for b in b_center - window..=b_center + window {
+            let y = x.b - Rational::new(Integer::new(b), Integer::new(s));
+            let base = x.a - Rational::new(Integer::new(b * t as i128), Integer::new(2));
+            // The norm of the difference (base - a) + y√D vanishes for base - a = ±√(Dy²)
+            let y_float = f64::from(y);
+            let root = (D as f64 * y_float * y_float).max(0.0).sqrt();
+            for target in [0.0, root, -root] {
+                let center = (f64::from(base) - target).floor() as i128;
+                for a in center - 1..=center + 2 {
+                    let q = QuadraticInteger::new(Integer::new(a), Integer::new(b));
+                    let norm = (*x - QuadraticField::from(q)).norm().abs();
+                    if best.as_ref().is_none_or(|(n, _)| norm < *n) {
+                        best = Some((norm, q));
+                    }
+                }
+            }
+        }
+        match best {
+            Some((norm, q)) if norm < Rational::one() => return q,
+            _ => window += 1,
+        }
+    }
+}
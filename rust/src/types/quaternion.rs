@@ -0,0 +1,325 @@
+use crate::traits::*;
+use std::fmt::Display;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Defines a [quaternion algebra](https://en.wikipedia.org/wiki/Quaternion_algebra) `(a, b)`,
+/// i.e. the multiplication of the basis `1, i, j, k` via `i² = a`, `j² = b` and `k = ij = -ji`.
+pub trait QuaternionAlgebra {
+    const A: i64;
+    const B: i64;
+}
+
+/// The [Hamilton quaternions](https://en.wikipedia.org/wiki/Quaternion) with `i² = j² = k² = ijk = -1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Hamilton;
+
+/// The [split quaternions](https://en.wikipedia.org/wiki/Split-quaternion) with `i² = -1` and `j² = k² = 1`,
+/// which are isomorphic to the 2x2 matrices and thus contain zero divisors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Split;
+
+impl QuaternionAlgebra for Hamilton {
+    const A: i64 = -1;
+    const B: i64 = -1;
+}
+
+impl QuaternionAlgebra for Split {
+    const A: i64 = -1;
+    const B: i64 = 1;
+}
+
+/// A quaternion `w + xi + yj + zk` with coefficients in the field `F`.
+///
+/// The multiplication is not commutative, so Quaternions are not `Commutative` and thus not a `Field`.
+/// The Hamilton quaternions over a [formally real](FormallyReal) field (e.g. over the Rationals)
+/// are a [`DivisionRing`], where `/` divides from the right (`p * q⁻¹`) and [`DivisionRing::left_divide`]
+/// divides from the left (`q⁻¹ * p`). In all other cases, only the elements with a non-zero norm are units.
+pub struct Quaternion<F, A = Hamilton> {
+    w: F,
+    x: F,
+    y: F,
+    z: F,
+    algebra: PhantomData<A>,
+}
+
+impl<F: Field, A: QuaternionAlgebra> Quaternion<F, A> {
+    pub fn new(w: F, x: F, y: F, z: F) -> Self {
+        Quaternion {
+            w,
+            x,
+            y,
+            z,
+            algebra: PhantomData,
+        }
+    }
+
+    pub fn i() -> Self {
+        Quaternion::new(F::zero(), F::one(), F::zero(), F::zero())
+    }
+
+    pub fn j() -> Self {
+        Quaternion::new(F::zero(), F::zero(), F::one(), F::zero())
+    }
+
+    pub fn k() -> Self {
+        Quaternion::new(F::zero(), F::zero(), F::zero(), F::one())
+    }
+
+    /// Returns the coefficients `(w, x, y, z)` of `w + xi + yj + zk`.
+    pub fn coefficients(&self) -> (&F, &F, &F, &F) {
+        (&self.w, &self.x, &self.y, &self.z)
+    }
+
+    /// The scalar part `w`.
+    pub fn real(&self) -> &F {
+        &self.w
+    }
+
+    /// The conjugate `w - xi - yj - zk`, which reverses products: `conj(pq) = conj(q) conj(p)`.
+    pub fn conjugate(&self) -> Self {
+        Quaternion::new(
+            self.w.clone(),
+            -self.x.clone(),
+            -self.y.clone(),
+            -self.z.clone(),
+        )
+    }
+
+    /// The (reduced) norm `q * conj(q) = w² - ax² - by² + abz²`, which is multiplicative.
+    /// For the Hamilton quaternions this is the sum of the squares of all coefficients.
+    pub fn norm(&self) -> F {
+        let (a, b) = (scalar::<F>(A::A), scalar::<F>(A::B));
+        self.w.clone() * self.w.clone()
+            - a.clone() * self.x.clone() * self.x.clone()
+            - b.clone() * self.y.clone() * self.y.clone()
+            + a * b * self.z.clone() * self.z.clone()
+    }
+
+    /// The (reduced) trace `q + conj(q) = 2w`.
+    pub fn trace(&self) -> F {
+        self.w.clone() + self.w.clone()
+    }
+
+    fn scale(&self, factor: &F) -> Self {
+        Quaternion::new(
+            self.w.clone() * factor.clone(),
+            self.x.clone() * factor.clone(),
+            self.y.clone() * factor.clone(),
+            self.z.clone() * factor.clone(),
+        )
+    }
+}
+
+/// Maps an integer into the field by repeated doubling of `1`.
+fn scalar<F: Ring>(n: i64) -> F {
+    let mut result = F::zero();
+    let mut power = F::one();
+    let mut m = n.unsigned_abs();
+    while m > 0 {
+        if m & 1 == 1 {
+            result += power.clone();
+        }
+        power = power.clone() + power;
+        m >>= 1;
+    }
+    if n < 0 {
+        -result
+    } else {
+        result
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> From<F> for Quaternion<F, A> {
+    fn from(value: F) -> Self {
+        Quaternion::new(value, F::zero(), F::zero(), F::zero())
+    }
+}
+
+// Implemented manually, as the derives would require the algebra marker to implement the traits as well
+impl<F: Clone, A> Clone for Quaternion<F, A> {
+    fn clone(&self) -> Self {
+        Quaternion {
+            w: self.w.clone(),
+            x: self.x.clone(),
+            y: self.y.clone(),
+            z: self.z.clone(),
+            algebra: PhantomData,
+        }
+    }
+}
+
+impl<F: Copy, A> Copy for Quaternion<F, A> {}
+
+impl<F: PartialEq, A> PartialEq for Quaternion<F, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.w == other.w && self.x == other.x && self.y == other.y && self.z == other.z
+    }
+}
+
+impl<F: Eq, A> Eq for Quaternion<F, A> {}
+
+impl<F: std::hash::Hash, A> std::hash::Hash for Quaternion<F, A> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (&self.w, &self.x, &self.y, &self.z).hash(state);
+    }
+}
+
+impl<F: std::fmt::Debug, A> std::fmt::Debug for Quaternion<F, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Quaternion")
+            .field("w", &self.w)
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
+
+impl<F: Field + Display, A: QuaternionAlgebra> Display for Quaternion<F, A> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let terms: Vec<String> = [
+            (&self.w, ""),
+            (&self.x, "i"),
+            (&self.y, "j"),
+            (&self.z, "k"),
+        ]
+        .into_iter()
+        .filter(|(c, _)| !c.is_zero())
+        .map(|(c, unit)| {
+            if c.is_one() && !unit.is_empty() {
+                unit.to_string()
+            } else {
+                format!("{c}{unit}")
+            }
+        })
+        .collect();
+        if terms.is_empty() {
+            write!(f, "0")
+        } else {
+            write!(f, "{}", terms.join(" + "))
+        }
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> Add for Quaternion<F, A> {
+    type Output = Quaternion<F, A>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w + rhs.w,
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z,
+        )
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> AddAssign for Quaternion<F, A> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> Sub for Quaternion<F, A> {
+    type Output = Quaternion<F, A>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Quaternion::new(
+            self.w - rhs.w,
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z,
+        )
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> SubAssign for Quaternion<F, A> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> Neg for Quaternion<F, A> {
+    type Output = Quaternion<F, A>;
+    fn neg(self) -> Self::Output {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> Mul for Quaternion<F, A> {
+    type Output = Quaternion<F, A>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let (a, b) = (scalar::<F>(A::A), scalar::<F>(A::B));
+        let ab = a.clone() * b.clone();
+        let (x0, x1, x2, x3) = (self.w, self.x, self.y, self.z);
+        let (y0, y1, y2, y3) = (rhs.w, rhs.x, rhs.y, rhs.z);
+        // Follows from i² = a, j² = b, k² = -ab, ij = -ji = k, jk = -kj = -bi and ki = -ik = -aj
+        Quaternion::new(
+            x0.clone() * y0.clone()
+                + a.clone() * x1.clone() * y1.clone()
+                + b.clone() * x2.clone() * y2.clone()
+                - ab * x3.clone() * y3.clone(),
+            x0.clone() * y1.clone() + x1.clone() * y0.clone()
+                - b.clone() * (x2.clone() * y3.clone() - x3.clone() * y2.clone()),
+            x0.clone() * y2.clone()
+                + x2.clone() * y0.clone()
+                + a * (x1.clone() * y3.clone() - x3.clone() * y1.clone()),
+            x0 * y3 + x3 * y0 + x1 * y2 - x2 * y1,
+        )
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> MulAssign for Quaternion<F, A> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs;
+    }
+}
+
+/// Right division, i.e. `p / q = p * q⁻¹`.
+impl<F: Field + FormallyReal> Div for Quaternion<F, Hamilton> {
+    type Output = Quaternion<F, Hamilton>;
+    fn div(self, rhs: Self) -> Self::Output {
+        DivisionRing::divide(&self, &rhs)
+    }
+}
+
+impl<F: Field + FormallyReal> DivAssign for Quaternion<F, Hamilton> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.clone() / rhs;
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> Set<Quaternion<F, A>> for Quaternion<F, A> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<F, A> Associative for Quaternion<F, A> {}
+impl<F, A> LeftDistributive for Quaternion<F, A> {}
+impl<F, A> RightDistributive for Quaternion<F, A> {}
+
+impl<F: Field, A: QuaternionAlgebra> NearRing for Quaternion<F, A> {
+    fn zero() -> Self {
+        Quaternion::from(F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.w.is_zero() && self.x.is_zero() && self.y.is_zero() && self.z.is_zero()
+    }
+}
+
+impl<F: Field, A: QuaternionAlgebra> Ring for Quaternion<F, A> {
+    fn one() -> Self {
+        Quaternion::from(F::one())
+    }
+
+    /// Computes the inverse as `conj(q) / N(q)`, i.e. the units are exactly the elements with non-zero norm.
+    fn inverse(&self) -> Option<Self> {
+        let norm = self.norm().inverse()?;
+        Some(self.conjugate().scale(&norm))
+    }
+}
+
+/// The norm of the Hamilton quaternions is a sum of squares, which is only zero for zero
+/// if the coefficients are formally real.
+impl<F: Field + FormallyReal> DivisionRing for Quaternion<F, Hamilton> {}
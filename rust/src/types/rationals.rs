@@ -0,0 +1,538 @@
+use super::Integer;
+use crate::traits::*;
+use crate::util::gcd;
+use std::cmp::Ordering;
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// An element of the Rationals *ℚ*.
+///
+/// Rationals are always stored in their reduced form, i.e. numerator and denominator are coprime
+/// and the denominator is positive. This makes the derived equality and hashing correct.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: Integer,
+    denom: Integer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseRationalErr {
+    Empty,
+    InvalidDigit,
+    ZeroDenominator,
+    Overflow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FromFloatErr {
+    /// The float is `NaN` or infinite.
+    NotFinite,
+    /// The exact value of the float can't be represented with the underlying Integers.
+    Overflow,
+}
+
+impl Rational {
+    /// Creates the Rational `numer / denom` in its reduced form.
+    ///
+    /// Panics if the denominator is zero.
+    pub fn new(numer: Integer, denom: Integer) -> Self {
+        if denom.is_zero() {
+            panic!("Divide by zero error")
+        }
+        let mut r = Rational { numer, denom };
+        r.normalize();
+        r
+    }
+
+    pub fn from_integer(value: Integer) -> Self {
+        Rational {
+            numer: value,
+            denom: Integer::one(),
+        }
+    }
+
+    pub fn numer(&self) -> &Integer {
+        &self.numer
+    }
+
+    pub fn denom(&self) -> &Integer {
+        &self.denom
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denom.is_one()
+    }
+
+    pub fn abs(&self) -> Self {
+        Rational {
+            numer: self.numer.abs(),
+            denom: self.denom,
+        }
+    }
+
+    pub fn signum(&self) -> Self {
+        Rational::from_integer(self.numer.signum())
+    }
+
+    /// Returns the largest Integer less than or equal to this Rational.
+    pub fn floor(&self) -> Integer {
+        // The denominator is positive, so the euclidean division rounds towards negative infinity
+        self.numer / self.denom
+    }
+
+    /// Returns the smallest Integer greater than or equal to this Rational.
+    pub fn ceil(&self) -> Integer {
+        -(-*self).floor()
+    }
+
+    /// Returns the nearest Integer to this Rational. Half-way cases are rounded away from zero.
+    pub fn round(&self) -> Integer {
+        let half = Rational::new(Integer::one(), Integer::new(2));
+        if self.numer.is_negative() {
+            (*self - half).ceil()
+        } else {
+            (*self + half).floor()
+        }
+    }
+
+    /// Returns the part of this Rational after the decimal point, i.e. `self - self.floor()`.
+    pub fn fract(&self) -> Self {
+        *self - Rational::from_integer(self.floor())
+    }
+
+    pub fn pow(&self, exp: i32) -> Self {
+        let r = if exp < 0 {
+            self.multiplicative_inverse()
+        } else {
+            *self
+        };
+        Rational {
+            numer: r.numer.pow(exp.unsigned_abs()),
+            denom: r.denom.pow(exp.unsigned_abs()),
+        }
+    }
+
+    fn normalize(&mut self) -> &mut Self {
+        let g = gcd(self.numer, self.denom) * self.denom.signum();
+        if !g.is_one() {
+            self.numer = self.numer.div_rem(&g).0;
+            self.denom = self.denom.div_rem(&g).0;
+        }
+        self
+    }
+}
+
+/// [Continued fractions](https://en.wikipedia.org/wiki/Continued_fraction) and rational approximations.
+///
+/// Every rational number has a finite simple continued fraction `[a₀; a₁, ..., aₙ] = a₀ + 1 / (a₁ + 1 / (... + 1 / aₙ))`
+/// with `aᵢ > 0` for `i > 0`. It is unique, if the last term is required to be greater than 1 (for `n > 0`).
+impl Rational {
+    /// Returns the terms `[a₀; a₁, ..., aₙ]` of the continued fraction of this number,
+    /// which are the quotients of the euclidean algorithm on numerator and denominator.
+    ///
+    /// The continued fraction is in its canonical form, i.e. the last term is greater than 1 (unless it is the only one).
+    pub fn continued_fraction(&self) -> Vec<Integer> {
+        let (mut p, mut q) = (*self.numer(), *self.denom());
+        let mut terms = Vec::new();
+        while !q.is_zero() {
+            // The remainder is non-negative, so the first quotient is the floor and all others are positive
+            let (a, r) = p.div_rem(&q);
+            terms.push(a);
+            (p, q) = (q, r);
+        }
+        terms
+    }
+
+    /// Evaluates the continued fraction `[a₀; a₁, ..., aₙ]`.
+    ///
+    /// Panics if there are no terms or the continued fraction evaluates to a zero denominator,
+    /// which can only happen if some term after the first is not positive.
+    pub fn from_continued_fraction(terms: &[Integer]) -> Self {
+        match convergents(terms).last() {
+            Some((h, k)) => Rational::new(*h, *k),
+            None => panic!("A continued fraction needs at least one term"),
+        }
+    }
+
+    /// Returns the convergents `[a₀; a₁, ..., aₖ]` for `k = 0, ..., n` of the continued fraction of this number.
+    ///
+    /// The convergents alternate around this number and approach it with increasing denominators;
+    /// the last convergent is the number itself. Each one is a best approximation, i.e. closer than any
+    /// other fraction with a smaller or equal denominator.
+    pub fn convergents(&self) -> Vec<Rational> {
+        convergents(&self.continued_fraction())
+            .into_iter()
+            .map(|(h, k)| Rational::new(h, k))
+            .collect()
+    }
+
+    /// Iterates over the semiconvergents of this number in order of increasing denominators.
+    ///
+    /// The semiconvergents are the fractions `(m * hₖ₋₁ + hₖ₋₂) / (m * kₖ₋₁ + kₖ₋₂)` for `1 <= m <= aₖ`,
+    /// where `hₖ / kₖ` are the convergents. For `m = aₖ` this is the convergent itself, so all convergents are included.
+    /// These are exactly the fractions visited when searching for this number in the
+    /// [Stern-Brocot tree](https://en.wikipedia.org/wiki/Stern%E2%80%93Brocot_tree), after the first convergent.
+    /// As there are `a₁ + ... + aₙ` of them, they are computed lazily.
+    pub fn semiconvergents(&self) -> impl Iterator<Item = Rational> {
+        let terms = self.continued_fraction();
+        let convergents = convergents(&terms);
+        let first = Rational::from_integer(terms[0]);
+        let rest = (1..terms.len()).flat_map(move |i| {
+            let (h1, k1) = convergents[i - 1];
+            let (h2, k2) = if i >= 2 {
+                convergents[i - 2]
+            } else {
+                (Integer::one(), Integer::zero())
+            };
+            (1..=terms[i].value()).map(move |m| {
+                let m = Integer::new(m);
+                Rational::new(m * h1 + h2, m * k1 + k2)
+            })
+        });
+        std::iter::once(first).chain(rest)
+    }
+
+    /// Returns the fraction closest to this number, whose denominator is at most `max_denom`.
+    /// If there are two closest fractions, the one with the smaller denominator is returned.
+    ///
+    /// This is either the last convergent or the last semiconvergent within the bound, which corresponds to
+    /// descending the [Stern-Brocot tree](https://en.wikipedia.org/wiki/Stern%E2%80%93Brocot_tree) until the denominators get too large.
+    ///
+    /// Panics if `max_denom` is not positive.
+    pub fn best_approximation(&self, max_denom: Integer) -> Rational {
+        if !max_denom.is_positive() {
+            panic!("The denominator bound has to be positive")
+        }
+        let terms = self.continued_fraction();
+        let convergents = convergents(&terms);
+        let Some(i) = convergents.iter().rposition(|(_, k)| *k <= max_denom) else {
+            unreachable!("The first convergent has denominator 1")
+        };
+        let (h1, k1) = convergents[i];
+        let convergent = Rational::new(h1, k1);
+        if i + 1 == convergents.len() {
+            return convergent;
+        }
+
+        // The largest semiconvergent between the i-th and (i+1)-th convergent within the bound
+        let (h2, k2) = if i >= 1 {
+            convergents[i - 1]
+        } else {
+            (Integer::one(), Integer::zero())
+        };
+        let m = (max_denom - k2) / k1;
+        if m.is_zero() {
+            return convergent;
+        }
+        let semiconvergent = Rational::new(m * h1 + h2, m * k1 + k2);
+        if (semiconvergent - *self).abs() < (convergent - *self).abs() {
+            semiconvergent
+        } else {
+            convergent
+        }
+    }
+
+    /// Approximates a float by the closest fraction, whose denominator is at most `max_denom`.
+    ///
+    /// The float is first converted exactly (see `TryFrom<f64>`), so this fails for the same values.
+    /// This is useful to recover "nice" coefficients (e.g. `1/3` from `0.333...`) before doing exact arithmetic.
+    ///
+    /// Panics if `max_denom` is not positive.
+    pub fn approximate_f64(value: f64, max_denom: Integer) -> Result<Rational, FromFloatErr> {
+        Rational::try_from(value).map(|exact| exact.best_approximation(max_denom))
+    }
+}
+
+/// Computes the numerators and denominators `(hₖ, kₖ)` of the convergents of `[a₀; a₁, ..., aₙ]` via
+/// `hₖ = aₖ * hₖ₋₁ + hₖ₋₂` and `kₖ = aₖ * kₖ₋₁ + kₖ₋₂`.
+fn convergents(terms: &[Integer]) -> Vec<(Integer, Integer)> {
+    let mut result = Vec::with_capacity(terms.len());
+    let (mut h2, mut k2) = (Integer::zero(), Integer::one());
+    let (mut h1, mut k1) = (Integer::one(), Integer::zero());
+    for a in terms {
+        let (h, k) = (*a * h1 + h2, *a * k1 + k2);
+        result.push((h, k));
+        (h2, k2, h1, k1) = (h1, k1, h, k);
+    }
+    result
+}
+
+impl From<Integer> for Rational {
+    fn from(value: Integer) -> Self {
+        Rational::from_integer(value)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Rational::from_integer(value.into())
+    }
+}
+
+/// Converts a float into the Rational with exactly the same value.
+impl TryFrom<f64> for Rational {
+    type Error = FromFloatErr;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(FromFloatErr::NotFinite);
+        }
+        if value == 0.0 {
+            return Ok(Rational::zero());
+        }
+        // Decompose the IEEE 754 representation into `mantissa * 2^exp`
+        let bits = value.to_bits();
+        let sign = if bits >> 63 == 0 { 1 } else { -1 };
+        let raw_exp = ((bits >> 52) & 0x7ff) as i32;
+        let fraction = (bits & ((1 << 52) - 1)) as i128;
+        let (mut mantissa, mut exp) = if raw_exp == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), raw_exp - 1075)
+        };
+        let shift = (mantissa.trailing_zeros() as i32).min(-exp.min(0));
+        mantissa >>= shift;
+        exp += shift;
+
+        if exp >= 0 {
+            if exp > 126 - (128 - mantissa.leading_zeros() as i32) {
+                return Err(FromFloatErr::Overflow);
+            }
+            Ok(Rational::from_integer(Integer::new(
+                sign * (mantissa << exp),
+            )))
+        } else {
+            if -exp > 126 {
+                return Err(FromFloatErr::Overflow);
+            }
+            Ok(Rational {
+                numer: Integer::new(sign * mantissa),
+                denom: Integer::new(1 << -exp),
+            })
+        }
+    }
+}
+
+impl From<Rational> for f64 {
+    fn from(value: Rational) -> Self {
+        value.numer.value() as f64 / value.denom.value() as f64
+    }
+}
+
+/// Parses fractions (`"-3/4"`) and decimal numbers (`"1.25"`, `"-0.5e-3"`).
+impl FromStr for Rational {
+    type Err = ParseRationalErr;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseRationalErr::Empty);
+        }
+        if let Some((numer, denom)) = s.split_once('/') {
+            let numer = parse_integer(numer.trim())?;
+            let denom = parse_integer(denom.trim())?;
+            if denom.is_zero() {
+                return Err(ParseRationalErr::ZeroDenominator);
+            }
+            return Ok(Rational::new(numer, denom));
+        }
+
+        let (mantissa, exp) = match s.find(['e', 'E']) {
+            Some(idx) => (
+                &s[..idx],
+                s[idx + 1..].parse::<i32>().map_err(parse_int_err)?,
+            ),
+            None => (s, 0),
+        };
+        let (negative, mantissa) = match mantissa.strip_prefix('-') {
+            Some(m) => (true, m),
+            None => (false, mantissa.strip_prefix('+').unwrap_or(mantissa)),
+        };
+        let (int_part, frac_part) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(ParseRationalErr::InvalidDigit);
+        }
+
+        let mut numer: i128 = 0;
+        for c in int_part.chars().chain(frac_part.chars()) {
+            let digit = c.to_digit(10).ok_or(ParseRationalErr::InvalidDigit)? as i128;
+            numer = numer
+                .checked_mul(10)
+                .and_then(|n| n.checked_add(digit))
+                .ok_or(ParseRationalErr::Overflow)?;
+        }
+        if numer == 0 {
+            return Ok(Rational::zero());
+        }
+        if negative {
+            numer = -numer;
+        }
+        let exp = i32::try_from(frac_part.len())
+            .ok()
+            .and_then(|len| exp.checked_sub(len))
+            .ok_or(ParseRationalErr::Overflow)?;
+        let power = 10i128
+            .checked_pow(exp.unsigned_abs())
+            .ok_or(ParseRationalErr::Overflow)?;
+        if exp >= 0 {
+            let numer = numer.checked_mul(power).ok_or(ParseRationalErr::Overflow)?;
+            Ok(Rational::from_integer(Integer::new(numer)))
+        } else {
+            Ok(Rational::new(Integer::new(numer), Integer::new(power)))
+        }
+    }
+}
+
+fn parse_integer(s: &str) -> Result<Integer, ParseRationalErr> {
+    if s.is_empty() {
+        return Err(ParseRationalErr::Empty);
+    }
+    s.parse().map_err(parse_int_err)
+}
+
+fn parse_int_err(e: std::num::ParseIntError) -> ParseRationalErr {
+    match e.kind() {
+        std::num::IntErrorKind::PosOverflow | std::num::IntErrorKind::NegOverflow => {
+            ParseRationalErr::Overflow
+        }
+        _ => ParseRationalErr::InvalidDigit,
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.numer * other.denom).cmp(&(other.numer * self.denom))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.denom == rhs.denom {
+            Rational::new(self.numer + rhs.numer, self.denom)
+        } else {
+            Rational::new(
+                self.numer * rhs.denom + rhs.numer * self.denom,
+                self.denom * rhs.denom,
+            )
+        }
+    }
+}
+
+impl AddAssign for Rational {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl SubAssign for Rational {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Self::Output {
+        Rational {
+            numer: -self.numer,
+            denom: self.denom,
+        }
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+    fn mul(self, rhs: Self) -> Self::Output {
+        // Cancel crosswise first to keep the intermediate values small
+        let g1 = gcd(self.numer, rhs.denom);
+        let g2 = gcd(rhs.numer, self.denom);
+        Rational {
+            numer: self.numer.div_rem(&g1).0 * rhs.numer.div_rem(&g2).0,
+            denom: self.denom.div_rem(&g2).0 * rhs.denom.div_rem(&g1).0,
+        }
+    }
+}
+
+impl MulAssign for Rational {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+    fn div(self, rhs: Self) -> Self::Output {
+        DivisionRing::divide(&self, &rhs)
+    }
+}
+
+impl DivAssign for Rational {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl Set<Rational> for Rational {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl Associative for Rational {}
+impl Commutative for Rational {}
+impl LeftDistributive for Rational {}
+impl RightDistributive for Rational {}
+impl FormallyReal for Rational {}
+
+impl MinusOneSquare for Rational {
+    const IS_SQUARE: bool = false;
+}
+
+impl NearRing for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(Integer::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer.is_zero()
+    }
+}
+
+impl Ring for Rational {
+    fn one() -> Self {
+        Rational::from_integer(Integer::one())
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(Rational::new(self.denom, self.numer))
+        }
+    }
+}
+
+impl DivisionRing for Rational {}
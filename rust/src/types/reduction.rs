@@ -0,0 +1,179 @@
+//! Backends for the modular reduction used by [`ZMod`](super::ZMod) and [`Zn`](super::Zn).
+//!
+//! Reducing the 128-bit product of two residues via `%` is the most expensive part of modular arithmetic.
+//! Both backends replace this division by a few multiplications with precomputed constants:
+//! - [Montgomery reduction](https://en.wikipedia.org/wiki/Montgomery_modular_multiplication) for odd moduli.
+//!   Residues are stored in *Montgomery form* (`a * 2^64 mod n`), which requires a conversion in and out.
+//! - [Barrett reduction](https://en.wikipedia.org/wiki/Barrett_reduction) for all other moduli.
+//!   Residues are stored as they are.
+//!
+//! All functions are `const`, so that the constants for a compile-time modulus can be computed at compile time.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Reducer {
+    Montgomery(Montgomery),
+    Barrett(Barrett),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Montgomery {
+    modulus: u64,
+    /// `n⁻¹ mod 2^64`
+    inverse: u64,
+    /// `2^128 mod n`, used to convert into Montgomery form
+    r2: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Barrett {
+    modulus: u64,
+    /// `⌊(2^128 - 1) / n⌋`
+    factor: u128,
+}
+
+impl Reducer {
+    /// Chooses the reduction backend for the modulus `n > 1`.
+    pub(crate) const fn new(modulus: u64) -> Self {
+        if modulus & 1 == 1 {
+            Reducer::Montgomery(Montgomery::new(modulus))
+        } else {
+            Reducer::Barrett(Barrett::new(modulus))
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn modulus(&self) -> u64 {
+        match self {
+            Reducer::Montgomery(montgomery) => montgomery.modulus,
+            Reducer::Barrett(barrett) => barrett.modulus,
+        }
+    }
+
+    /// Converts a value `x < n` into the internal representation of the backend.
+    #[inline]
+    pub(crate) const fn encode(&self, x: u64) -> u64 {
+        match self {
+            Reducer::Montgomery(montgomery) => montgomery.mul(x, montgomery.r2),
+            Reducer::Barrett(_) => x,
+        }
+    }
+
+    /// Converts the internal representation of the backend back into the value `x < n`.
+    #[inline]
+    pub(crate) const fn decode(&self, x: u64) -> u64 {
+        match self {
+            Reducer::Montgomery(montgomery) => montgomery.reduce(x as u128),
+            Reducer::Barrett(_) => x,
+        }
+    }
+
+    /// Multiplies two values in their internal representation.
+    #[inline]
+    pub(crate) const fn mul(&self, a: u64, b: u64) -> u64 {
+        match self {
+            Reducer::Montgomery(montgomery) => montgomery.mul(a, b),
+            Reducer::Barrett(barrett) => barrett.mul(a, b),
+        }
+    }
+
+    /// Adds two values in their internal representation.
+    /// As both representations are linear, this is the usual modular addition.
+    #[inline]
+    pub(crate) const fn add(&self, a: u64, b: u64) -> u64 {
+        let n = self.modulus();
+        let (s, carry) = a.overflowing_add(b);
+        if carry || s >= n {
+            s.wrapping_sub(n)
+        } else {
+            s
+        }
+    }
+
+    #[inline]
+    pub(crate) const fn neg(&self, a: u64) -> u64 {
+        if a == 0 {
+            0
+        } else {
+            self.modulus() - a
+        }
+    }
+}
+
+impl Montgomery {
+    const fn new(modulus: u64) -> Self {
+        // Newton's iteration doubles the number of correct bits in each step,
+        // starting with 3 correct bits, as n * n = 1 mod 8 for all odd n.
+        let mut inverse = modulus;
+        let mut i = 0;
+        while i < 5 {
+            inverse = inverse.wrapping_mul(2u64.wrapping_sub(modulus.wrapping_mul(inverse)));
+            i += 1;
+        }
+        let r = (1u128 << 64) % modulus as u128;
+        Montgomery {
+            modulus,
+            inverse,
+            r2: (r * r % modulus as u128) as u64,
+        }
+    }
+
+    /// Computes `t * 2^(-64) mod n` for `t < n * 2^64`.
+    #[inline]
+    const fn reduce(&self, t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(self.inverse);
+        let mn = m as u128 * self.modulus as u128;
+        // The lower 64 bits of t and m * n are equal, so t - m * n is divisible by 2^64
+        let (t_hi, mn_hi) = ((t >> 64) as u64, (mn >> 64) as u64);
+        if t_hi >= mn_hi {
+            t_hi - mn_hi
+        } else {
+            t_hi.wrapping_add(self.modulus).wrapping_sub(mn_hi)
+        }
+    }
+
+    #[inline]
+    const fn mul(&self, a: u64, b: u64) -> u64 {
+        self.reduce(a as u128 * b as u128)
+    }
+}
+
+impl Barrett {
+    const fn new(modulus: u64) -> Self {
+        Barrett {
+            modulus,
+            factor: u128::MAX / modulus as u128,
+        }
+    }
+
+    #[inline]
+    const fn mul(&self, a: u64, b: u64) -> u64 {
+        let x = a as u128 * b as u128;
+        // q is at most 3 smaller than ⌊x / n⌋ < n
+        let q = mul_hi_approx(x, self.factor) as u64;
+        let n = self.modulus as u128;
+        let mut r = x - q as u128 * n;
+        if r >= n {
+            r -= n;
+            if r >= n {
+                r -= n;
+                if r >= n {
+                    r -= n;
+                }
+            }
+        }
+        r as u64
+    }
+}
+
+/// Approximates the upper 128 bits of the 256-bit product `a * b`.
+/// As the product of the lower halves is ignored, the result might be too small by 1.
+#[inline]
+const fn mul_hi_approx(a: u128, b: u128) -> u128 {
+    let (a_lo, a_hi) = (a as u64 as u128, (a >> 64) as u64 as u128);
+    let (b_lo, b_hi) = (b as u64 as u128, (b >> 64) as u64 as u128);
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_hi = a_hi * b_hi;
+    let mid = (hi_lo as u64 as u128) + (lo_hi as u64 as u128);
+    hi_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64)
+}
@@ -0,0 +1,730 @@
+use super::polynomial::{format_term, mul_by_usize};
+use super::{Polynomial, PolynomialDivErr, Variable, X};
+use crate::traits::*;
+use crate::util::inverse_mod;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+/// A univariate polynomial, which only stores its non-zero terms.
+///
+/// The terms are stored as pairs `(pow, coef)` sorted by their powers, so that e.g. `x^1000000 + 1` only needs two terms.
+/// Sparse polynomials provide the same arithmetic as the dense [`Polynomial`], into which they can be converted
+/// via `From` (and back). The division only touches the non-zero terms as well, which makes reducing modulo
+/// trinomials or pentanomials cheap.
+pub struct SparsePolynomial<R, V = X> {
+    terms: Vec<(usize, R)>,
+    variable: PhantomData<V>,
+}
+
+impl<R: Ring, V: Variable> SparsePolynomial<R, V> {
+    /// Creates the zero polynomial.
+    pub fn new() -> Self {
+        SparsePolynomial {
+            terms: Vec::new(),
+            variable: PhantomData,
+        }
+    }
+
+    /// Creates a polynomial from its terms `(pow, coef)` in any order.
+    /// The coefficients of equal powers are added up.
+    pub fn from_terms(terms: Vec<(usize, R)>) -> Self {
+        let mut sums = BTreeMap::new();
+        for (pow, coef) in terms {
+            *sums.entry(pow).or_insert_with(R::zero) += coef;
+        }
+        SparsePolynomial::from_map(sums)
+    }
+
+    /// Creates the monomial `coef * x^pow`.
+    pub fn monomial(coef: R, pow: usize) -> Self {
+        SparsePolynomial::from_terms(vec![(pow, coef)])
+    }
+
+    /// Returns the polynomial `x`, i.e. the variable `V` itself.
+    pub fn x() -> Self {
+        SparsePolynomial::monomial(R::one(), 1)
+    }
+
+    /// Returns the same polynomial in another variable.
+    pub fn with_variable<W: Variable>(self) -> SparsePolynomial<R, W> {
+        SparsePolynomial {
+            terms: self.terms,
+            variable: PhantomData,
+        }
+    }
+
+    /// Iterates over the non-zero terms as pairs `(pow, coef)` in ascending order of their powers.
+    pub fn terms(&self) -> impl Iterator<Item = (usize, &R)> {
+        self.terms.iter().map(|(pow, coef)| (*pow, coef))
+    }
+
+    pub fn into_terms(self) -> Vec<(usize, R)> {
+        self.terms
+    }
+
+    /// Returns the degree, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.terms.last().map(|(pow, _)| *pow)
+    }
+
+    /// Returns the number of non-zero terms.
+    pub fn len(&self) -> usize {
+        self.terms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Returns the coefficient of `x^pow`.
+    pub fn coef(&self, pow: usize) -> R {
+        match self.terms.binary_search_by_key(&pow, |(p, _)| *p) {
+            Ok(i) => self.terms[i].1.clone(),
+            Err(_) => R::zero(),
+        }
+    }
+
+    /// Sets the coefficient of `x^pow`.
+    pub fn set_coef(&mut self, pow: usize, coef: R) {
+        match self.terms.binary_search_by_key(&pow, |(p, _)| *p) {
+            Ok(i) if coef.is_zero() => {
+                self.terms.remove(i);
+            }
+            Ok(i) => self.terms[i].1 = coef,
+            Err(_) if coef.is_zero() => {}
+            Err(i) => self.terms.insert(i, (pow, coef)),
+        }
+    }
+
+    /// Returns the coefficient of the highest power, or `None` for the zero polynomial.
+    pub fn leading_coef(&self) -> Option<&R> {
+        self.terms.last().map(|(_, coef)| coef)
+    }
+
+    pub fn is_monic(&self) -> bool {
+        self.leading_coef().is_some_and(|c| c.is_one())
+    }
+
+    /// Multiplies this polynomial with `x^amount`.
+    pub fn shl(&mut self, amount: usize) -> &mut Self {
+        self.terms.iter_mut().for_each(|(pow, _)| *pow += amount);
+        self
+    }
+
+    /// Divides this polynomial by `x^amount`, dropping all terms of smaller powers.
+    pub fn shr(&mut self, amount: usize) -> &mut Self {
+        self.terms.retain(|(pow, _)| *pow >= amount);
+        self.terms.iter_mut().for_each(|(pow, _)| *pow -= amount);
+        self
+    }
+
+    /// Multiplies this polynomial with the monomial `coef * x^pow`, where the coefficient is multiplied from the right.
+    pub fn simple_mul(&self, coef: &R, pow: usize) -> Self {
+        SparsePolynomial::from_terms(
+            self.terms
+                .iter()
+                .map(|(p, c)| (p + pow, c.clone() * coef.clone()))
+                .collect(),
+        )
+    }
+
+    /// Returns `self * self`.
+    pub fn square(&self) -> Self {
+        self * self
+    }
+
+    /// Computes `self^exp` via repeated squaring.
+    ///
+    /// Powers of monomials (e.g. `x^(q^k)`) stay a single term, so they can be reduced cheaply afterwards.
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut result = SparsePolynomial::one();
+        for bit in (0..u64::BITS - exp.leading_zeros()).rev() {
+            result = result.square();
+            if (exp >> bit) & 1 == 1 {
+                result *= self;
+            }
+        }
+        result
+    }
+
+    /// Evaluates the polynomial at `x` via Horner's method, where the gaps between the terms are bridged by
+    /// [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring).
+    pub fn eval(&self, x: &R) -> R {
+        let mut value = R::zero();
+        let mut last_pow = self.degree().unwrap_or(0);
+        for (pow, coef) in self.terms.iter().rev() {
+            value = value * pow_ring(x, last_pow - pow) + coef.clone();
+            last_pow = *pow;
+        }
+        value * pow_ring(x, last_pow)
+    }
+
+    /// Returns the formal [derivative](https://en.wikipedia.org/wiki/Formal_derivative).
+    pub fn derivative(&self) -> Self {
+        SparsePolynomial::from_terms(
+            self.terms
+                .iter()
+                .filter(|(pow, _)| *pow > 0)
+                .map(|(pow, c)| (pow - 1, mul_by_usize(c, *pow)))
+                .collect(),
+        )
+    }
+
+    fn from_map(map: BTreeMap<usize, R>) -> Self {
+        SparsePolynomial {
+            terms: map.into_iter().filter(|(_, c)| !c.is_zero()).collect(),
+            variable: PhantomData,
+        }
+    }
+
+    /// Merges the terms of `rhs` into the terms of `self`, where `op` adds (or subtracts) coefficients of equal powers.
+    fn merge(&mut self, rhs: &Self, op: impl Fn(R, R) -> R) {
+        let mut terms = Vec::with_capacity(self.terms.len() + rhs.terms.len());
+        let mut lhs = std::mem::take(&mut self.terms).into_iter().peekable();
+        let mut rhs = rhs.terms.iter().peekable();
+        loop {
+            let next = match (lhs.peek(), rhs.peek()) {
+                (Some((p, _)), Some((q, _))) if p < q => lhs.next().unwrap(),
+                (Some((p, _)), Some((q, _))) if p > q => {
+                    let (q, c) = rhs.next().unwrap();
+                    (*q, op(R::zero(), c.clone()))
+                }
+                (Some(_), Some(_)) => {
+                    let (p, c1) = lhs.next().unwrap();
+                    let (_, c2) = rhs.next().unwrap();
+                    (p, op(c1, c2.clone()))
+                }
+                (Some(_), None) => lhs.next().unwrap(),
+                (None, Some(_)) => {
+                    let (q, c) = rhs.next().unwrap();
+                    (*q, op(R::zero(), c.clone()))
+                }
+                (None, None) => break,
+            };
+            if !next.1.is_zero() {
+                terms.push(next);
+            }
+        }
+        self.terms = terms;
+    }
+}
+
+impl<R: Ring, V: Variable> SparsePolynomial<R, V> {
+    /// Divides with remainder from the right like [`SparsePolynomial::right_div_rem`], which is possible over any ring,
+    /// as long as the leading coefficient of `rhs` is a unit (e.g. for monic divisors).
+    pub fn try_div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialDivErr> {
+        let lead_inverse = rhs
+            .leading_coef()
+            .ok_or(PolynomialDivErr::DivideByZero)?
+            .inverse()
+            .ok_or(PolynomialDivErr::NonUnitLeadingCoefficient)?;
+        self.long_division(rhs, |lead, _| Ok(lead.clone() * lead_inverse.clone()), true)
+    }
+
+    /// Polynomial long division, which only iterates over the non-zero terms of the remainder.
+    /// `quotient` computes the next coefficient of the quotient from the leading coefficients of the remainder
+    /// and the divisor. The quotient's terms are multiplied from the left, if `right` is set.
+    fn long_division(
+        &self,
+        rhs: &Self,
+        quotient: impl Fn(&R, &R) -> Result<R, PolynomialDivErr>,
+        right: bool,
+    ) -> Result<(Self, Self), PolynomialDivErr> {
+        let Some(&(divisor_degree, ref divisor_lead)) = rhs.terms.last() else {
+            return Err(PolynomialDivErr::DivideByZero);
+        };
+        let mut rem: BTreeMap<usize, R> = self.terms.iter().cloned().collect();
+        let mut quot = Vec::new();
+        while let Some((&pow, _)) = rem.last_key_value() {
+            if pow < divisor_degree {
+                break;
+            }
+            // The leading term cancels by construction, so it is removed instead of relying on exact arithmetic
+            let (_, lead) = rem.pop_last().unwrap();
+            let c = quotient(&lead, divisor_lead)?;
+            let shift = pow - divisor_degree;
+            for (e, d) in &rhs.terms[..rhs.terms.len() - 1] {
+                let product = if right {
+                    c.clone() * d.clone()
+                } else {
+                    d.clone() * c.clone()
+                };
+                let entry = rem.entry(shift + e).or_insert_with(R::zero);
+                *entry -= product;
+                if entry.is_zero() {
+                    rem.remove(&(shift + e));
+                }
+            }
+            if !c.is_zero() {
+                quot.push((shift, c));
+            }
+        }
+        quot.reverse();
+        Ok((
+            SparsePolynomial {
+                terms: quot,
+                variable: PhantomData,
+            },
+            SparsePolynomial::from_map(rem),
+        ))
+    }
+}
+
+impl<R: CommutativeRing, V: Variable> SparsePolynomial<R, V> {
+    /// [Pseudo-division](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor#Pseudo-remainder_sequences),
+    /// i.e. returns `(q, r)` with `lc(rhs)ᵏ * self = q * rhs + r` and `deg(r) < deg(rhs)`, where `k = max(deg(self) - deg(rhs) + 1, 0)`.
+    ///
+    /// The same as [`Polynomial::pseudo_div_rem`], but the steps, in which the remainder has no term of the
+    /// current degree, only scale by `lc(rhs)`. This scaling is postponed and applied once at the end.
+    pub fn pseudo_div_rem(&self, rhs: &Self) -> Result<(Self, Self), PolynomialDivErr> {
+        let Some(&(divisor_degree, ref divisor_lead)) = rhs.terms.last() else {
+            return Err(PolynomialDivErr::DivideByZero);
+        };
+        let Some(degree) = self.degree().filter(|d| *d >= divisor_degree) else {
+            return Ok((SparsePolynomial::new(), self.clone()));
+        };
+
+        // In each step: q ← lc·q + lead·xˢ and r ← lc·r - lead·xˢ·rhs
+        let mut rem: BTreeMap<usize, R> = self.terms.iter().cloned().collect();
+        let mut quot: Vec<(usize, R)> = Vec::new();
+        let mut skipped = 0;
+        let mut next_shift = degree - divisor_degree + 1;
+        while let Some((&pow, _)) = rem.last_key_value() {
+            if pow < divisor_degree {
+                break;
+            }
+            let shift = pow - divisor_degree;
+            skipped += next_shift - shift - 1;
+            next_shift = shift;
+            let (_, lead) = rem.pop_last().unwrap();
+            quot.iter_mut()
+                .for_each(|(_, c)| *c *= divisor_lead.clone());
+            rem.values_mut().for_each(|c| *c *= divisor_lead.clone());
+            for (e, d) in &rhs.terms[..rhs.terms.len() - 1] {
+                *rem.entry(shift + e).or_insert_with(R::zero) -= lead.clone() * d.clone();
+            }
+            // Over rings with zero divisors the scaling may cancel terms as well
+            rem.retain(|_, c| !c.is_zero());
+            quot.push((shift, lead));
+        }
+        skipped += next_shift;
+
+        let scale = pow_ring(divisor_lead, skipped);
+        Ok((
+            SparsePolynomial::from_terms(quot).simple_mul(&scale, 0),
+            SparsePolynomial::from_map(rem).simple_mul(&scale, 0),
+        ))
+    }
+}
+
+impl<R: EuclideanDomain, V: Variable> SparsePolynomial<R, V> {
+    /// Returns the quotient `self / rhs`, if `rhs` divides `self`, see [`Polynomial::exact_div`].
+    ///
+    /// Fails with [`PolynomialDivErr::NotExact`] otherwise, instead of truncating any coefficients.
+    pub fn exact_div(&self, rhs: &Self) -> Result<Self, PolynomialDivErr> {
+        let (quot, rem) = self.long_division(
+            rhs,
+            |lead, divisor_lead| match lead.div_rem(divisor_lead) {
+                (q, r) if r.is_zero() => Ok(q),
+                _ => Err(PolynomialDivErr::NotExact),
+            },
+            true,
+        )?;
+        if rem.is_zero() {
+            Ok(quot)
+        } else {
+            Err(PolynomialDivErr::NotExact)
+        }
+    }
+}
+
+impl<R: DivisionRing, V: Variable> SparsePolynomial<R, V> {
+    /// Divides with remainder from the right, i.e. returns `(q, r)` with `self = q * rhs + r` and `deg(r) < deg(rhs)`.
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn right_div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.long_division(
+            rhs,
+            |lead, divisor_lead| Ok(R::divide(lead, divisor_lead)),
+            true,
+        )
+        .unwrap_or_else(|_| panic!("Divide by zero error"))
+    }
+
+    /// Divides with remainder from the left, i.e. returns `(q, r)` with `self = rhs * q + r` and `deg(r) < deg(rhs)`.
+    ///
+    /// Panics if `rhs` is zero.
+    pub fn left_div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.long_division(
+            rhs,
+            |lead, divisor_lead| Ok(R::left_divide(lead, divisor_lead)),
+            false,
+        )
+        .unwrap_or_else(|_| panic!("Divide by zero error"))
+    }
+
+    /// Scales this polynomial, such that it is monic. The zero polynomial stays zero.
+    pub fn monic(&self) -> Self {
+        match self.leading_coef() {
+            Some(lead) => self.simple_mul(&lead.multiplicative_inverse(), 0),
+            None => self.clone(),
+        }
+    }
+}
+
+impl<F: Field, V: Variable> SparsePolynomial<F, V> {
+    /// Returns the inverse of this polynomial in the quotient ring *F\[x\]/(modulus)*, if it is coprime to `modulus`.
+    ///
+    /// The inverse has a smaller degree than `modulus`. See [`inverse_mod`].
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        inverse_mod(self.clone(), modulus.clone())
+    }
+}
+
+/// Computes `base^exp` via exponentiation by squaring.
+fn pow_ring<R: Ring>(base: &R, exp: usize) -> R {
+    let mut result = R::one();
+    let mut base = base.clone();
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result *= base.clone();
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base *= base.clone();
+        }
+    }
+    result
+}
+
+// Implemented manually, so that the variable marker doesn't need to implement these traits
+
+impl<R: Clone, V> Clone for SparsePolynomial<R, V> {
+    fn clone(&self) -> Self {
+        SparsePolynomial {
+            terms: self.terms.clone(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: PartialEq, V> PartialEq for SparsePolynomial<R, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.terms == other.terms
+    }
+}
+
+impl<R: Eq, V> Eq for SparsePolynomial<R, V> {}
+
+impl<R: Hash, V> Hash for SparsePolynomial<R, V> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.terms.hash(state);
+    }
+}
+
+impl<R: Debug, V: Variable> Debug for SparsePolynomial<R, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SparsePolynomial")
+            .field("terms", &self.terms)
+            .field("variable", &V::NAME)
+            .finish()
+    }
+}
+
+impl<R: Ring, V: Variable> Default for SparsePolynomial<R, V> {
+    fn default() -> Self {
+        SparsePolynomial::new()
+    }
+}
+
+impl<R: Ring, V: Variable> From<R> for SparsePolynomial<R, V> {
+    fn from(value: R) -> Self {
+        SparsePolynomial::monomial(value, 0)
+    }
+}
+
+impl<R: Ring, V: Variable> From<Polynomial<R, V>> for SparsePolynomial<R, V> {
+    fn from(value: Polynomial<R, V>) -> Self {
+        SparsePolynomial {
+            terms: value.terms().map(|(pow, c)| (pow, c.clone())).collect(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: Ring, V: Variable> From<SparsePolynomial<R, V>> for Polynomial<R, V> {
+    fn from(value: SparsePolynomial<R, V>) -> Self {
+        let mut coefs = vec![R::zero(); value.degree().map_or(0, |d| d + 1)];
+        for (pow, coef) in value.terms {
+            coefs[pow] = coef;
+        }
+        Polynomial::from_vec(coefs)
+    }
+}
+
+/// Shows the terms in descending order like [`Polynomial`], e.g. `x^1000 + 2x + 1`.
+impl<R: Ring + Display, V: Variable> Display for SparsePolynomial<R, V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_zero() {
+            return write!(f, "{}", R::zero());
+        }
+        let terms: Vec<String> = self
+            .terms
+            .iter()
+            .rev()
+            .map(|(pow, coef)| match pow {
+                0 => format_term(coef, None),
+                1 => format_term(coef, Some(V::NAME)),
+                _ => format_term(coef, Some(&format!("{}^{pow}", V::NAME))),
+            })
+            .collect();
+        write!(f, "{}", terms.join(" + "))
+    }
+}
+
+impl<R: Ring, V: Variable> AddAssign<&SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    fn add_assign(&mut self, rhs: &SparsePolynomial<R, V>) {
+        self.merge(rhs, |c1, c2| c1 + c2);
+    }
+}
+
+impl<R: Ring, V: Variable> AddAssign for SparsePolynomial<R, V> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Add<&SparsePolynomial<R, V>> for &SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn add(self, rhs: &SparsePolynomial<R, V>) -> Self::Output {
+        let mut p = self.clone();
+        p += rhs;
+        p
+    }
+}
+
+impl<R: Ring, V: Variable> Add<&SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn add(mut self, rhs: &SparsePolynomial<R, V>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> Add for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += &rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> SubAssign<&SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    fn sub_assign(&mut self, rhs: &SparsePolynomial<R, V>) {
+        self.merge(rhs, |c1, c2| c1 - c2);
+    }
+}
+
+impl<R: Ring, V: Variable> SubAssign for SparsePolynomial<R, V> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> Sub<&SparsePolynomial<R, V>> for &SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn sub(self, rhs: &SparsePolynomial<R, V>) -> Self::Output {
+        let mut p = self.clone();
+        p -= rhs;
+        p
+    }
+}
+
+impl<R: Ring, V: Variable> Sub<&SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn sub(mut self, rhs: &SparsePolynomial<R, V>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> Sub for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= &rhs;
+        self
+    }
+}
+
+impl<R: Ring, V: Variable> Neg for &SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn neg(self) -> Self::Output {
+        SparsePolynomial {
+            terms: self.terms.iter().map(|(p, c)| (*p, -c.clone())).collect(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: Ring, V: Variable> Neg for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn neg(self) -> Self::Output {
+        SparsePolynomial {
+            terms: self.terms.into_iter().map(|(p, c)| (p, -c)).collect(),
+            variable: PhantomData,
+        }
+    }
+}
+
+impl<R: Ring, V: Variable> Mul<&SparsePolynomial<R, V>> for &SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn mul(self, rhs: &SparsePolynomial<R, V>) -> Self::Output {
+        let mut products = BTreeMap::new();
+        for (p, a) in &self.terms {
+            for (q, b) in &rhs.terms {
+                *products.entry(p + q).or_insert_with(R::zero) += a.clone() * b.clone();
+            }
+        }
+        SparsePolynomial::from_map(products)
+    }
+}
+
+impl<R: Ring, V: Variable> Mul<&SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn mul(self, rhs: &SparsePolynomial<R, V>) -> Self::Output {
+        &self * rhs
+    }
+}
+
+impl<R: Ring, V: Variable> Mul for SparsePolynomial<R, V> {
+    type Output = SparsePolynomial<R, V>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        &self * &rhs
+    }
+}
+
+impl<R: Ring, V: Variable> MulAssign<&SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    fn mul_assign(&mut self, rhs: &SparsePolynomial<R, V>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<R: Ring, V: Variable> MulAssign for SparsePolynomial<R, V> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = &*self * &rhs;
+    }
+}
+
+/// The quotient of the euclidean division, see [`EuclideanDomain::div_rem`].
+impl<F: Field, V: Variable> Div<&SparsePolynomial<F, V>> for &SparsePolynomial<F, V> {
+    type Output = SparsePolynomial<F, V>;
+    fn div(self, rhs: &SparsePolynomial<F, V>) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+
+impl<F: Field, V: Variable> Div for SparsePolynomial<F, V> {
+    type Output = SparsePolynomial<F, V>;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).0
+    }
+}
+
+impl<F: Field, V: Variable> DivAssign<&SparsePolynomial<F, V>> for SparsePolynomial<F, V> {
+    fn div_assign(&mut self, rhs: &SparsePolynomial<F, V>) {
+        *self = self.div_rem(rhs).0;
+    }
+}
+
+impl<F: Field, V: Variable> DivAssign for SparsePolynomial<F, V> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).0;
+    }
+}
+
+/// The remainder of the euclidean division, see [`EuclideanDomain::div_rem`].
+impl<F: Field, V: Variable> Rem<&SparsePolynomial<F, V>> for &SparsePolynomial<F, V> {
+    type Output = SparsePolynomial<F, V>;
+    fn rem(self, rhs: &SparsePolynomial<F, V>) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+impl<F: Field, V: Variable> Rem for SparsePolynomial<F, V> {
+    type Output = SparsePolynomial<F, V>;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(&rhs).1
+    }
+}
+
+impl<F: Field, V: Variable> RemAssign<&SparsePolynomial<F, V>> for SparsePolynomial<F, V> {
+    fn rem_assign(&mut self, rhs: &SparsePolynomial<F, V>) {
+        *self = self.div_rem(rhs).1;
+    }
+}
+
+impl<F: Field, V: Variable> RemAssign for SparsePolynomial<F, V> {
+    fn rem_assign(&mut self, rhs: Self) {
+        *self = self.div_rem(&rhs).1;
+    }
+}
+
+impl<R: Ring, V: Variable> Set<SparsePolynomial<R, V>> for SparsePolynomial<R, V> {
+    fn size(&self) -> SetSize {
+        SetSize::CountablyInfinite
+    }
+}
+
+impl<R: Ring, V: Variable> Associative for SparsePolynomial<R, V> {}
+impl<R: Ring + Commutative, V: Variable> Commutative for SparsePolynomial<R, V> {}
+impl<R: Ring, V: Variable> LeftDistributive for SparsePolynomial<R, V> {}
+impl<R: Ring, V: Variable> RightDistributive for SparsePolynomial<R, V> {}
+
+impl<R: Ring, V: Variable> NearRing for SparsePolynomial<R, V> {
+    fn zero() -> Self {
+        SparsePolynomial::new()
+    }
+
+    fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+}
+
+impl<R: Ring, V: Variable> Ring for SparsePolynomial<R, V> {
+    fn one() -> Self {
+        SparsePolynomial::from(R::one())
+    }
+
+    /// Returns the inverse of constant polynomials with a unit coefficient, see [`Polynomial`]'s `inverse`.
+    fn inverse(&self) -> Option<Self> {
+        match self.terms.as_slice() {
+            [(0, c)] => c.inverse().map(SparsePolynomial::from),
+            _ => None,
+        }
+    }
+}
+
+impl<F: Field, V: Variable> EuclideanDomain for SparsePolynomial<F, V> {
+    fn div_rem(&self, rhs: &Self) -> (Self, Self) {
+        self.right_div_rem(rhs)
+    }
+
+    /// The degree plus one, so that the zero polynomial has the smallest value.
+    fn euclidean_value(&self) -> u128 {
+        self.degree().map_or(0, |d| d as u128 + 1)
+    }
+
+    /// The inverse of the leading coefficient, so that the unit normal form is monic.
+    fn normalizing_unit(&self) -> Self {
+        match self.leading_coef() {
+            Some(lead) => SparsePolynomial::from(lead.multiplicative_inverse()),
+            None => SparsePolynomial::one(),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use super::{Natural, ZMod, ZnElement, ZnErr};
+use crate::number_theory::euler_phi;
+use crate::traits::*;
+use std::fmt::Display;
+
+/// A [unit](https://en.wikipedia.org/wiki/Unit_(ring_theory)) of a ring, seen as an element of the ring's
+/// multiplicative group (e.g. *(ℤ/nℤ)^\**).
+///
+/// Units are created via `TryFrom`, which fails for non-units. Wrapping the element makes the multiplication available as the operation of the [`Group`] trait,
+/// so that group algorithms (e.g. [discrete logarithms](crate::number_theory::discrete_log)) can be applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Unit<T>(T);
+
+/// The group of units modulo `modulus`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitGroup {
+    modulus: u64,
+}
+
+impl<T> Unit<T> {
+    pub fn value(&self) -> &T {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<const N: u64> TryFrom<ZMod<N>> for Unit<ZMod<N>> {
+    type Error = ZnErr;
+    fn try_from(value: ZMod<N>) -> Result<Self, Self::Error> {
+        if value.is_unit() {
+            Ok(Unit(value))
+        } else {
+            Err(ZnErr::NotInvertible)
+        }
+    }
+}
+
+impl TryFrom<ZnElement> for Unit<ZnElement> {
+    type Error = ZnErr;
+    fn try_from(value: ZnElement) -> Result<Self, Self::Error> {
+        value.inverse().ok_or(ZnErr::NotInvertible)?;
+        Ok(Unit(value))
+    }
+}
+
+impl<T: Display> Display for Unit<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl UnitGroup {
+    pub fn new(modulus: u64) -> Self {
+        UnitGroup { modulus }
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+}
+
+impl<const N: u64> Set<Unit<ZMod<N>>> for UnitGroup {
+    /// The size is Euler's totient of the modulus.
+    fn size(&self) -> SetSize {
+        SetSize::Finite(euler_phi(Natural::new(self.modulus)).value())
+    }
+
+    fn contains(&self, item: &Unit<ZMod<N>>) -> bool {
+        self.modulus == N && item.0.is_unit()
+    }
+}
+
+impl Set<Unit<ZnElement>> for UnitGroup {
+    /// The size is Euler's totient of the modulus.
+    fn size(&self) -> SetSize {
+        SetSize::Finite(euler_phi(Natural::new(self.modulus)).value())
+    }
+
+    fn contains(&self, item: &Unit<ZnElement>) -> bool {
+        self.modulus == item.0.modulus() && item.0.inverse().is_some()
+    }
+}
+
+impl Display for UnitGroup {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(Z/{}Z)^*", self.modulus)
+    }
+}
+
+impl<T> Associative for Unit<T> {}
+impl<T: Commutative> Commutative for Unit<T> {}
+
+impl<const N: u64> AlgebraicStructure for Unit<ZMod<N>> {
+    type Domain = UnitGroup;
+    type Output = Self;
+
+    fn domain(&self) -> Self::Domain {
+        UnitGroup::new(N)
+    }
+
+    fn op(&self, rhs: &Self) -> Self::Output {
+        Unit(self.0 * rhs.0)
+    }
+
+    fn mut_op(&mut self, rhs: &Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl<const N: u64> Magma for Unit<ZMod<N>> {}
+impl<const N: u64> SemiGroup for Unit<ZMod<N>> {}
+
+impl<const N: u64> Monoid for Unit<ZMod<N>> {
+    fn identity(&self) -> Self {
+        Unit(ZMod::one())
+    }
+}
+
+impl<const N: u64> Group for Unit<ZMod<N>> {
+    fn inverse(&self) -> Self {
+        Unit(Ring::inverse(&self.0).expect("Units are invertible"))
+    }
+}
+
+impl AlgebraicStructure for Unit<ZnElement> {
+    type Domain = UnitGroup;
+    type Output = Self;
+
+    fn domain(&self) -> Self::Domain {
+        UnitGroup::new(self.0.modulus())
+    }
+
+    fn op(&self, rhs: &Self) -> Self::Output {
+        Unit(self.0 * rhs.0)
+    }
+
+    fn mut_op(&mut self, rhs: &Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl Magma for Unit<ZnElement> {}
+impl SemiGroup for Unit<ZnElement> {}
+
+impl Monoid for Unit<ZnElement> {
+    fn identity(&self) -> Self {
+        Unit(self.0.ring().one())
+    }
+}
+
+impl Group for Unit<ZnElement> {
+    fn inverse(&self) -> Self {
+        Unit(self.0.inverse().expect("Units are invertible"))
+    }
+}
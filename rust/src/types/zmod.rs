@@ -0,0 +1,269 @@
+use super::reduction::Reducer;
+use super::{Integer, Natural};
+use crate::number_theory::{is_prime_u64, legendre_symbol, sqrt_mod_known_prime};
+use crate::traits::*;
+use crate::util::{extended_gcd, ntt_convolve, ExtendedGcd, NTT_THRESHOLD};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// An element of the ring of integers modulo `N` (*ℤ/Nℤ*), where the modulus is known at compile time.
+///
+/// Depending on the modulus, residues are stored in Montgomery form (for odd `N`) or as
+/// their smallest non-negative representative. This is hidden behind [`ZMod::new`] and [`ZMod::value`].
+///
+/// `ZMod<N>` is a commutative ring for every `N > 1`. If `N` is prime it is also a field. As a const generic
+/// can't be bounded by a predicate like `is_prime_u64(N)`, the field operations (e.g. the division) are implemented
+/// for every `N`, but using them with a composite modulus fails at compile time.
+/// Inverses of units are available for every modulus via [`Ring::inverse`].
+///
+/// ```compile_fail
+/// use polydiv::types::ZMod;
+/// // 4 is not prime, so there is no division
+/// let _ = ZMod::<4>::new(1) / ZMod::<4>::new(3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ZMod<const N: u64>(u64);
+
+impl<const N: u64> ZMod<N> {
+    pub const MODULUS: u64 = N;
+
+    const VALID_MODULUS: () = assert!(N > 1, "The modulus of ZMod has to be greater than 1");
+    const PRIME_MODULUS: () = assert!(
+        is_prime_u64(N),
+        "ZMod<N> is only a field if the modulus N is prime"
+    );
+    const REDUCER: Reducer = Reducer::new(N);
+
+    pub fn new(value: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::VALID_MODULUS;
+        ZMod(Self::REDUCER.encode(value % N))
+    }
+
+    /// Returns the smallest non-negative representative of this residue class.
+    pub fn value(&self) -> u64 {
+        Self::REDUCER.decode(self.0)
+    }
+
+    /// Returns whether `ZMod<N>` is a field, i.e. whether `N` is prime.
+    pub fn is_field() -> bool {
+        is_prime_u64(N)
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut base = *self;
+        let mut exp = exp;
+        let mut result = Self::one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    /// The [Legendre symbol](https://en.wikipedia.org/wiki/Legendre_symbol) of this element,
+    /// i.e. `1` for non-zero squares, `-1` for non-squares and `0` for zero.
+    ///
+    /// Only available for a prime modulus, which is checked at compile time.
+    pub fn legendre(&self) -> i8 {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::PRIME_MODULUS;
+        if N == 2 {
+            return self.value() as i8;
+        }
+        legendre_symbol(Integer::from(*self), Natural::new(N))
+    }
+
+    /// Returns whether this element is a square (including zero).
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// Returns a square root of this element, if it is a square.
+    ///
+    /// The other root is its negation; the one with the smaller representative is returned.
+    /// See [`sqrt_mod_prime`](crate::number_theory::sqrt_mod_prime) for the algorithms used. Only available for a prime modulus, like [`ZMod::legendre`].
+    pub fn sqrt(&self) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::PRIME_MODULUS;
+        sqrt_mod_known_prime(Natural::new(self.value()), Natural::new(N))
+            .map(|r| ZMod::new(r.value()))
+    }
+}
+
+impl<const N: u64> From<u64> for ZMod<N> {
+    fn from(value: u64) -> Self {
+        ZMod::new(value)
+    }
+}
+
+impl<const N: u64> From<Integer> for ZMod<N> {
+    fn from(value: Integer) -> Self {
+        ZMod::new(value.value().rem_euclid(N as i128) as u64)
+    }
+}
+
+impl<const N: u64> From<ZMod<N>> for Integer {
+    fn from(value: ZMod<N>) -> Self {
+        Integer::from(value.value())
+    }
+}
+
+impl<const N: u64> PartialOrd for ZMod<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the smallest non-negative representatives.
+impl<const N: u64> Ord for ZMod<N> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.value().cmp(&other.value())
+    }
+}
+
+impl<const N: u64> Display for ZMod<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl<const N: u64> Add for ZMod<N> {
+    type Output = ZMod<N>;
+    fn add(self, rhs: Self) -> Self::Output {
+        ZMod(Self::REDUCER.add(self.0, rhs.0))
+    }
+}
+
+impl<const N: u64> AddAssign for ZMod<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: u64> Sub for ZMod<N> {
+    type Output = ZMod<N>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + (-rhs)
+    }
+}
+
+impl<const N: u64> SubAssign for ZMod<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: u64> Neg for ZMod<N> {
+    type Output = ZMod<N>;
+    fn neg(self) -> Self::Output {
+        ZMod(Self::REDUCER.neg(self.0))
+    }
+}
+
+impl<const N: u64> Mul for ZMod<N> {
+    type Output = ZMod<N>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        ZMod(Self::REDUCER.mul(self.0, rhs.0))
+    }
+}
+
+impl<const N: u64> MulAssign for ZMod<N> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const N: u64> Div for ZMod<N> {
+    type Output = ZMod<N>;
+    fn div(self, rhs: Self) -> Self::Output {
+        DivisionRing::divide(&self, &rhs)
+    }
+}
+
+impl<const N: u64> DivAssign for ZMod<N> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// The set of all residues modulo `N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Residues<const N: u64>;
+
+impl<const N: u64> IntoIterator for Residues<N> {
+    type Item = ZMod<N>;
+    type IntoIter = std::iter::Map<std::ops::Range<u64>, fn(u64) -> ZMod<N>>;
+    fn into_iter(self) -> Self::IntoIter {
+        (0..N).map(ZMod::new)
+    }
+}
+
+impl<const N: u64> EnumerableSet<ZMod<N>> for Residues<N> {
+    fn size(&self) -> SetSize {
+        SetSize::Finite(N)
+    }
+}
+
+impl<const N: u64> Associative for ZMod<N> {}
+impl<const N: u64> Commutative for ZMod<N> {}
+impl<const N: u64> LeftDistributive for ZMod<N> {}
+impl<const N: u64> RightDistributive for ZMod<N> {}
+
+impl<const N: u64> NearRing for ZMod<N> {
+    fn zero() -> Self {
+        ZMod::new(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const N: u64> Ring for ZMod<N> {
+    fn one() -> Self {
+        ZMod::new(1)
+    }
+
+    /// Computes the modular inverse via the extended euclidean algorithm.
+    fn inverse(&self) -> Option<Self> {
+        let ExtendedGcd { gcd, x, .. } = extended_gcd(Integer::from(*self), Integer::from(N));
+        if gcd.is_one() {
+            Some(x.into())
+        } else {
+            None
+        }
+    }
+
+    /// Uses the number-theoretic transform for long operands, if `N` is a suitable prime (see [`ntt_convolve`]).
+    fn convolve(a: &[Self], b: &[Self]) -> Option<Vec<Self>> {
+        if a.len().min(b.len()) < NTT_THRESHOLD {
+            return None;
+        }
+        ntt_convolve(a, b)
+    }
+}
+
+/// Fails to compile for a composite modulus.
+impl<const N: u64> DivisionRing for ZMod<N> {
+    fn multiplicative_inverse(&self) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let _ = Self::PRIME_MODULUS;
+        self.inverse().expect("Divide by zero error")
+    }
+}
+
+/// For a prime `p`, `-1` is a square modulo `p` iff `p = 2` or `p ≡ 1 (mod 4)`.
+/// Like the field operations, this fails to compile for a composite modulus.
+impl<const N: u64> MinusOneSquare for ZMod<N> {
+    const IS_SQUARE: bool = {
+        assert!(
+            is_prime_u64(N),
+            "ZMod<N> is only a field if the modulus N is prime"
+        );
+        N % 4 != 3
+    };
+}
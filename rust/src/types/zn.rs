@@ -0,0 +1,303 @@
+use super::reduction::Reducer;
+use super::Integer;
+use crate::number_theory::is_prime_u64;
+use crate::traits::*;
+use crate::util::{extended_gcd, ExtendedGcd};
+use std::fmt::Display;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// The ring of integers modulo `n` (*ℤ/nℤ*), where the modulus is only known at runtime.
+///
+/// This is the parent object of the ring's elements ([`ZnElement`]). It owns the modulus
+/// together with some precomputed constants, that make the reduction after each multiplication cheaper
+/// (Montgomery reduction for odd moduli and Barrett reduction otherwise).
+/// If the modulus is known at compile time, [`ZMod`](super::ZMod) should be preferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Zn {
+    modulus: u64,
+    reducer: Reducer,
+}
+
+/// An element of [`Zn`].
+///
+/// Like [`ZMod`](super::ZMod), the residue might be stored in Montgomery form,
+/// which is hidden behind [`Zn::element`] and [`ZnElement::value`].
+///
+/// Each element carries its parent, so that the modulus of both operands can be compared
+/// in every operation. The operators panic when mixing residues of different moduli,
+/// while the `checked_*` methods report a [`ZnErr::ModulusMismatch`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ZnElement {
+    repr: u64,
+    ring: Zn,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZnErr {
+    /// The modulus has to be greater than 1.
+    InvalidModulus,
+    /// The operands of an operation are residues of different moduli.
+    ModulusMismatch { lhs: u64, rhs: u64 },
+    /// The divisor (or any other element, that is required to be invertible) is not a unit modulo *n*.
+    NotInvertible,
+}
+
+impl Display for ZnErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ZnErr::InvalidModulus => write!(f, "the modulus has to be greater than 1"),
+            ZnErr::ModulusMismatch { lhs, rhs } => {
+                write!(f, "the operands are residues modulo {lhs} and {rhs}")
+            }
+            ZnErr::NotInvertible => write!(f, "the element is not a unit"),
+        }
+    }
+}
+
+impl std::error::Error for ZnErr {}
+
+impl Zn {
+    pub fn new(modulus: u64) -> Result<Self, ZnErr> {
+        if modulus < 2 {
+            return Err(ZnErr::InvalidModulus);
+        }
+        Ok(Zn {
+            modulus,
+            reducer: Reducer::new(modulus),
+        })
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.modulus
+    }
+
+    /// Returns whether this ring is a field, i.e. whether the modulus is prime.
+    ///
+    /// The primality test is only run here instead of in [`Zn::new`], which keeps the construction cheap.
+    pub fn is_field(&self) -> bool {
+        is_prime_u64(self.modulus)
+    }
+
+    #[inline]
+    pub fn element(&self, value: u64) -> ZnElement {
+        ZnElement {
+            repr: self.reducer.encode(value % self.modulus),
+            ring: *self,
+        }
+    }
+
+    pub fn from_integer(&self, value: Integer) -> ZnElement {
+        self.element(value.value().rem_euclid(self.modulus as i128) as u64)
+    }
+
+    pub fn zero(&self) -> ZnElement {
+        self.element(0)
+    }
+
+    pub fn one(&self) -> ZnElement {
+        self.element(1)
+    }
+
+    /// Iterates over all elements of this ring.
+    pub fn elements(&self) -> impl Iterator<Item = ZnElement> + '_ {
+        (0..self.modulus).map(|value| self.element(value))
+    }
+}
+
+impl Set<ZnElement> for Zn {
+    fn size(&self) -> SetSize {
+        SetSize::Finite(self.modulus)
+    }
+
+    fn contains(&self, item: &ZnElement) -> bool {
+        item.ring == *self
+    }
+}
+
+impl Display for Zn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Z/{}Z", self.modulus)
+    }
+}
+
+impl ZnElement {
+    /// Returns the smallest non-negative representative of this residue class.
+    #[inline]
+    pub fn value(&self) -> u64 {
+        self.ring.reducer.decode(self.repr)
+    }
+
+    pub fn ring(&self) -> &Zn {
+        &self.ring
+    }
+
+    pub fn modulus(&self) -> u64 {
+        self.ring.modulus
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.repr == 0
+    }
+
+    pub fn is_one(&self) -> bool {
+        self.value() == 1
+    }
+
+    #[inline]
+    fn check_modulus(&self, rhs: &Self) -> Result<(), ZnErr> {
+        if self.ring.modulus == rhs.ring.modulus {
+            Ok(())
+        } else {
+            Err(ZnErr::ModulusMismatch {
+                lhs: self.ring.modulus,
+                rhs: rhs.ring.modulus,
+            })
+        }
+    }
+
+    #[inline]
+    pub fn checked_add(&self, rhs: &Self) -> Result<Self, ZnErr> {
+        self.check_modulus(rhs)?;
+        Ok(self.with_repr(self.ring.reducer.add(self.repr, rhs.repr)))
+    }
+
+    #[inline]
+    pub fn checked_sub(&self, rhs: &Self) -> Result<Self, ZnErr> {
+        self.check_modulus(rhs)?;
+        self.checked_add(&-*rhs)
+    }
+
+    #[inline]
+    pub fn checked_mul(&self, rhs: &Self) -> Result<Self, ZnErr> {
+        self.check_modulus(rhs)?;
+        Ok(self.with_repr(self.ring.reducer.mul(self.repr, rhs.repr)))
+    }
+
+    #[inline]
+    pub fn checked_div(&self, rhs: &Self) -> Result<Self, ZnErr> {
+        self.check_modulus(rhs)?;
+        self.checked_mul(&rhs.inverse().ok_or(ZnErr::NotInvertible)?)
+    }
+
+    /// Returns the multiplicative inverse of this element, if it is a unit.
+    pub fn inverse(&self) -> Option<Self> {
+        let ExtendedGcd { gcd, x, .. } = extended_gcd(
+            Integer::from(self.value()),
+            Integer::from(self.ring.modulus),
+        );
+        if gcd.is_one() {
+            Some(self.ring.from_integer(x))
+        } else {
+            None
+        }
+    }
+
+    pub fn pow(&self, exp: u64) -> Self {
+        let mut base = *self;
+        let mut exp = exp;
+        let mut result = self.ring.one();
+        while exp > 0 {
+            if exp & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exp >>= 1;
+        }
+        result
+    }
+
+    #[inline]
+    fn with_repr(&self, repr: u64) -> Self {
+        ZnElement {
+            repr,
+            ring: self.ring,
+        }
+    }
+}
+
+impl From<ZnElement> for Integer {
+    fn from(value: ZnElement) -> Self {
+        Integer::from(value.value())
+    }
+}
+
+impl Display for ZnElement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+impl Associative for ZnElement {}
+impl Commutative for ZnElement {}
+impl LeftDistributive for ZnElement {}
+impl RightDistributive for ZnElement {}
+
+impl Add for ZnElement {
+    type Output = ZnElement;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        self.checked_add(&rhs).expect("Modulus mismatch error")
+    }
+}
+
+impl AddAssign for ZnElement {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for ZnElement {
+    type Output = ZnElement;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.checked_sub(&rhs).expect("Modulus mismatch error")
+    }
+}
+
+impl SubAssign for ZnElement {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for ZnElement {
+    type Output = ZnElement;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        self.with_repr(self.ring.reducer.neg(self.repr))
+    }
+}
+
+impl Mul for ZnElement {
+    type Output = ZnElement;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.checked_mul(&rhs).expect("Modulus mismatch error")
+    }
+}
+
+impl MulAssign for ZnElement {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for ZnElement {
+    type Output = ZnElement;
+    fn div(self, rhs: Self) -> Self::Output {
+        match self.checked_div(&rhs) {
+            Ok(q) => q,
+            Err(ZnErr::NotInvertible) => panic!("Divisor is not invertible"),
+            Err(_) => panic!("Modulus mismatch error"),
+        }
+    }
+}
+
+impl DivAssign for ZnElement {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
@@ -0,0 +1,10 @@
+mod crt;
+mod fft;
+mod gcd;
+mod ntt;
+mod reconstruction;
+pub use crt::*;
+pub use fft::*;
+pub use gcd::*;
+pub use ntt::*;
+pub use reconstruction::*;
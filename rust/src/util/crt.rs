@@ -0,0 +1,88 @@
+use super::{extended_gcd, ExtendedGcd};
+use crate::traits::EuclideanDomain;
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrtErr {
+    /// One of the moduli is zero.
+    ZeroModulus,
+    /// Two of the moduli are not coprime (only reported by [`crt`]).
+    NotCoprime,
+    /// The congruences don't have a common solution (only reported by [`crt_general`]).
+    Inconsistent,
+}
+
+impl Display for CrtErr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CrtErr::ZeroModulus => write!(f, "one of the moduli is zero"),
+            CrtErr::NotCoprime => write!(f, "the moduli are not pairwise coprime"),
+            CrtErr::Inconsistent => write!(f, "the congruences have no common solution"),
+        }
+    }
+}
+
+impl std::error::Error for CrtErr {}
+
+/// Solves the system of congruences `x ≡ aᵢ (mod mᵢ)` for pairwise coprime moduli
+/// via the [Chinese Remainder Theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem).
+///
+/// The congruences are given as pairs `(aᵢ, mᵢ)`. Returns the solution `x` together with the combined modulus `m`,
+/// which is the product of all moduli. The solution is reduced modulo `m` (i.e. `x` is the remainder of the division by `m`).
+/// The empty system has the solution `x = 0 (mod 1)`.
+///
+/// This works in every euclidean domain, e.g. for Integers or for Polynomials over a field.
+pub fn crt<A>(congruences: &[(A, A)]) -> Result<(A, A), CrtErr>
+where
+    A: EuclideanDomain,
+{
+    solve(congruences, true)
+}
+
+/// Solves the system of congruences `x ≡ aᵢ (mod mᵢ)` for arbitrary (non-zero) moduli.
+///
+/// Unlike [`crt`], the moduli don't need to be coprime. Instead it is checked, whether
+/// the congruences agree modulo the gcd of their moduli, in which case the combined modulus is the lcm of all moduli.
+/// Otherwise [`CrtErr::Inconsistent`] is returned.
+pub fn crt_general<A>(congruences: &[(A, A)]) -> Result<(A, A), CrtErr>
+where
+    A: EuclideanDomain,
+{
+    solve(congruences, false)
+}
+
+fn solve<A>(congruences: &[(A, A)], coprime: bool) -> Result<(A, A), CrtErr>
+where
+    A: EuclideanDomain,
+{
+    let mut result = (A::zero(), A::one());
+    for (residue, modulus) in congruences {
+        if modulus.is_zero() {
+            return Err(CrtErr::ZeroModulus);
+        }
+        result = combine(result, (residue.clone(), modulus.clone()), coprime)?;
+    }
+    Ok(result)
+}
+
+/// Combines `x ≡ a₁ (mod m₁)` and `x ≡ a₂ (mod m₂)` into a single congruence.
+fn combine<A>((a1, m1): (A, A), (a2, m2): (A, A), coprime: bool) -> Result<(A, A), CrtErr>
+where
+    A: EuclideanDomain,
+{
+    let ExtendedGcd { gcd, x, .. } = extended_gcd(m1.clone(), m2.clone());
+    if coprime && !gcd.is_one() {
+        return Err(CrtErr::NotCoprime);
+    }
+
+    // With x * m₁ + y * m₂ = g, the solution is a₁ + m₁ * x * (a₂ - a₁) / g (mod lcm(m₁, m₂))
+    let (diff, rem) = (a2 - a1.clone()).div_rem(&gcd);
+    if !rem.is_zero() {
+        return Err(CrtErr::Inconsistent);
+    }
+    let m2_reduced = m2.div_rem(&gcd).0;
+    let factor = (diff * x).div_rem(&m2_reduced).1;
+    let modulus = (m1.clone() * m2_reduced).unit_normal();
+    let residue = (a1 + m1 * factor).div_rem(&modulus).1;
+    Ok((residue, modulus))
+}
@@ -0,0 +1,81 @@
+use super::bit_reverse_permutation;
+use crate::traits::*;
+use crate::types::{Complex, Float};
+use std::f64::consts::PI;
+
+/// From this length of the shorter operand on, [`Ring::convolve`] uses the fast Fourier transform for [`Float`] coefficients.
+pub(crate) const FFT_THRESHOLD: usize = 256;
+
+/// The in-place iterative [fast Fourier transform](https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm)
+/// `Xₖ = Σ xⱼ e^(∓2πijk/n)`, where the sign is positive for the inverse transform.
+/// The inverse transform is not scaled by `1/n`.
+///
+/// Panics if the length is not a power of two.
+pub fn fft(values: &mut [Complex<Float>], inverse: bool) {
+    let n = values.len();
+    assert!(
+        n.is_power_of_two(),
+        "The length of the FFT has to be a power of two"
+    );
+    let sign = if inverse { 1.0 } else { -1.0 };
+    // Computing each twiddle factor directly avoids accumulating rounding errors
+    let twiddles: Vec<Complex<Float>> = (0..n / 2)
+        .map(|j| {
+            let (sin, cos) = (sign * 2.0 * PI * j as f64 / n as f64).sin_cos();
+            Complex::new(Float::new(cos), Float::new(sin))
+        })
+        .collect();
+    bit_reverse_permutation(values);
+    let mut len = 2;
+    while len <= n {
+        let stride = n / len;
+        for chunk in values.chunks_mut(len) {
+            let (lower, upper) = chunk.split_at_mut(len / 2);
+            for (j, (u, v)) in lower.iter_mut().zip(upper).enumerate() {
+                let t = *v * twiddles[j * stride];
+                *v = *u - t;
+                *u += t;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Computes the convolution of complex sequences via the fast Fourier transform.
+///
+/// The result is subject to rounding errors, which grow with the length and the magnitude of the inputs.
+pub fn fft_convolve_complex(a: &[Complex<Float>], b: &[Complex<Float>]) -> Vec<Complex<Float>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    fa.resize(size, Complex::zero());
+    fft(&mut fa, false);
+    if std::ptr::eq(a, b) {
+        fa.iter_mut().for_each(|x| *x = *x * *x);
+    } else {
+        let mut fb = b.to_vec();
+        fb.resize(size, Complex::zero());
+        fft(&mut fb, false);
+        fa.iter_mut().zip(fb).for_each(|(x, y)| *x *= y);
+    }
+    fft(&mut fa, true);
+    fa.truncate(len);
+    let scale = Complex::from(Float::new(1.0 / size as f64));
+    fa.iter_mut().for_each(|x| *x *= scale);
+    fa
+}
+
+/// Computes the convolution of real sequences via the fast Fourier transform, see [`fft_convolve_complex`].
+pub fn fft_convolve(a: &[Float], b: &[Float]) -> Vec<Float> {
+    let complex = |v: &[Float]| v.iter().map(|x| Complex::from(*x)).collect::<Vec<_>>();
+    let ca = complex(a);
+    let product = if std::ptr::eq(a, b) {
+        fft_convolve_complex(&ca, &ca)
+    } else {
+        fft_convolve_complex(&ca, &complex(b))
+    };
+    product.into_iter().map(|x| *x.re()).collect()
+}
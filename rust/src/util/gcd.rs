@@ -0,0 +1,149 @@
+use crate::traits::EuclideanDomain;
+
+/// The result of the [extended euclidean algorithm](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm).
+///
+/// The fields satisfy `gcd = x * a + y * b` (also known as *Bézout's identity*).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedGcd<A> {
+    pub gcd: A,
+    pub x: A,
+    pub y: A,
+}
+
+/// Computes the greatest common divisor of `a` and `b` together with the Bézout cofactors `x` and `y`.
+///
+/// The returned gcd is normalized via [`EuclideanDomain::unit_normal`] (e.g. it is non-negative for integers)
+/// and the cofactors are adjusted accordingly.
+pub fn extended_gcd<A>(a: A, b: A) -> ExtendedGcd<A>
+where
+    A: EuclideanDomain,
+{
+    // Algorithm adapted from https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm
+    let mut r = a;
+    let mut s = b;
+    let mut x = A::one();
+    let mut u = A::zero();
+    let mut y = A::zero();
+    let mut v = A::one();
+
+    while !s.is_zero() {
+        let (quot, rem) = r.div_rem(&s);
+        (r, s) = (s, rem);
+        let next_u = x - quot.clone() * u.clone();
+        (x, u) = (u, next_u);
+        let next_v = y - quot * v.clone();
+        (y, v) = (v, next_v);
+    }
+
+    let unit = r.normalizing_unit();
+    ExtendedGcd {
+        gcd: r * unit.clone(),
+        x: x * unit.clone(),
+        y: y * unit,
+    }
+}
+
+/// Runs the extended euclidean algorithm on `a` and `b` only until the remainder becomes small.
+///
+/// Returns the first remainder `r` with `r.euclidean_value() <= bound` together with its cofactor `y`,
+/// such that `r ≡ y * b (mod a)`. Only the cofactor of `b` is tracked (the *half-extended* euclidean algorithm),
+/// which is all that's needed for [rational reconstruction](super::rational_reconstruction).
+/// Neither `r` nor `y` is normalized.
+pub fn half_extended_gcd<A>(a: A, b: A, bound: u128) -> (A, A)
+where
+    A: EuclideanDomain,
+{
+    let mut r = a;
+    let mut s = b;
+    let mut y = A::zero();
+    let mut v = A::one();
+
+    while r.euclidean_value() > bound && !s.is_zero() {
+        let (quot, rem) = r.div_rem(&s);
+        (r, s) = (s, rem);
+        let next_v = y - quot * v.clone();
+        (y, v) = (v, next_v);
+    }
+    if r.euclidean_value() > bound {
+        // The gcd itself is still too large, so the next remainder is 0
+        (s, v)
+    } else {
+        (r, y)
+    }
+}
+
+/// Computes the greatest common divisor of `a` and `b`.
+///
+/// The result is normalized via [`EuclideanDomain::unit_normal`].
+pub fn gcd<A>(a: A, b: A) -> A
+where
+    A: EuclideanDomain,
+{
+    let mut a = a;
+    let mut b = b;
+    while !b.is_zero() {
+        let rem = a.div_rem(&b).1;
+        (a, b) = (b, rem)
+    }
+    a.unit_normal()
+}
+
+/// Computes the least common multiple of `a` and `b`.
+///
+/// The result is normalized via [`EuclideanDomain::unit_normal`] and zero if either argument is zero.
+pub fn lcm<A>(a: A, b: A) -> A
+where
+    A: EuclideanDomain,
+{
+    if a.is_zero() || b.is_zero() {
+        return A::zero();
+    }
+    let g = gcd(a.clone(), b.clone());
+    (a.div_rem(&g).0 * b).unit_normal()
+}
+
+/// Computes the greatest common divisor of all `values`, which is zero for no values.
+///
+/// The result is normalized via [`EuclideanDomain::unit_normal`].
+pub fn gcd_all<A>(values: impl IntoIterator<Item = A>) -> A
+where
+    A: EuclideanDomain,
+{
+    let mut result = A::zero();
+    for value in values {
+        result = gcd(result, value);
+        if result.is_one() {
+            break;
+        }
+    }
+    result
+}
+
+/// Computes the least common multiple of all `values`, which is one for no values.
+///
+/// The result is normalized via [`EuclideanDomain::unit_normal`].
+pub fn lcm_all<A>(values: impl IntoIterator<Item = A>) -> A
+where
+    A: EuclideanDomain,
+{
+    values.into_iter().fold(A::one(), lcm)
+}
+
+/// Computes the inverse of `a` modulo `modulus`, i.e. `x` with `x * a ≡ 1 (mod modulus)`, if `a` and `modulus` are coprime.
+///
+/// The inverse is reduced via [`EuclideanDomain::div_rem`] (e.g. its degree is smaller than the modulus' for polynomials).
+/// For a zero modulus this is the inverse of `a` itself.
+pub fn inverse_mod<A>(a: A, modulus: A) -> Option<A>
+where
+    A: EuclideanDomain,
+{
+    if modulus.is_zero() {
+        return a.inverse();
+    }
+    let ExtendedGcd { gcd, x, .. } = extended_gcd(a, modulus.clone());
+    if gcd.is_one() {
+        Some(x.div_rem(&modulus).1)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,220 @@
+use crate::number_theory::is_prime_u64;
+use crate::traits::*;
+use crate::types::{Integer, ZMod};
+
+/// From this length of the shorter operand on, [`Ring::convolve`] uses the number-theoretic transform
+/// for `ZMod` and `Integer` coefficients.
+pub(crate) const NTT_THRESHOLD: usize = 128;
+
+/// Primes `p = c·2ᵏ + 1` with a large power of two dividing `p - 1`, which are used for [`ntt_convolve_integers`].
+const NTT_PRIMES: [u64; 4] = [998244353, 754974721, 469762049, 167772161];
+
+/// Computes the convolution of `a` and `b` (i.e. the coefficients of the product of the polynomials with these
+/// coefficients) via the [number-theoretic transform](https://en.wikipedia.org/wiki/Discrete_Fourier_transform_over_a_ring).
+///
+/// This requires `N` to be a prime, such that `2ᵏ` divides `N - 1` for a power of two `2ᵏ ≥ a.len() + b.len() - 1`,
+/// e.g. `998244353 = 119·2²³ + 1`. Otherwise `None` is returned.
+pub fn ntt_convolve<const N: u64>(a: &[ZMod<N>], b: &[ZMod<N>]) -> Option<Vec<ZMod<N>>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(Vec::new());
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    if N == 2 || (N - 1).trailing_zeros() < size.trailing_zeros() || !is_prime_u64(N) {
+        return None;
+    }
+    let root = root_of_unity::<N>(size);
+
+    let mut fa = a.to_vec();
+    fa.resize(size, ZMod::zero());
+    ntt(&mut fa, root);
+    if std::ptr::eq(a, b) {
+        fa.iter_mut().for_each(|x| *x = *x * *x);
+    } else {
+        let mut fb = b.to_vec();
+        fb.resize(size, ZMod::zero());
+        ntt(&mut fb, root);
+        fa.iter_mut().zip(fb).for_each(|(x, y)| *x *= y);
+    }
+
+    // The inverse transform is the transform with the inverse root, scaled by 1/size
+    ntt(&mut fa, root.inverse()?);
+    let size_inv = ZMod::<N>::new(size as u64).inverse()?;
+    fa.truncate(len);
+    fa.iter_mut().for_each(|x| *x *= size_inv);
+    Some(fa)
+}
+
+/// Computes the convolution of integer sequences via number-theoretic transforms modulo several primes,
+/// whose results are combined via the Chinese remainder theorem.
+///
+/// The number of primes is chosen based on a bound of the resulting coefficients. Returns `None` if the
+/// coefficients might not be representable with the available primes (i.e. if they might exceed about `2¹¹⁴`).
+pub fn ntt_convolve_integers(a: &[Integer], b: &[Integer]) -> Option<Vec<Integer>> {
+    if a.is_empty() || b.is_empty() {
+        return Some(Vec::new());
+    }
+    let max_abs = |v: &[Integer]| {
+        v.iter()
+            .map(|x| x.value().unsigned_abs())
+            .max()
+            .unwrap_or(0)
+    };
+    let bound = max_abs(a)
+        .checked_mul(max_abs(b))?
+        .checked_mul(a.len().min(b.len()) as u128)?;
+
+    // The symmetric residues modulo the product of the primes have to cover [-bound, bound]
+    let mut modulus: u128 = 1;
+    let mut primes = 0;
+    while modulus / 2 <= bound {
+        modulus = modulus.checked_mul(*NTT_PRIMES.get(primes)? as u128)?;
+        primes += 1;
+    }
+
+    let residues: Vec<Vec<u64>> = [
+        convolve_mod::<{ NTT_PRIMES[0] }>,
+        convolve_mod::<{ NTT_PRIMES[1] }>,
+        convolve_mod::<{ NTT_PRIMES[2] }>,
+        convolve_mod::<{ NTT_PRIMES[3] }>,
+    ][..primes]
+        .iter()
+        .map(|convolve| convolve(a, b))
+        .collect::<Option<_>>()?;
+
+    let garner = Garner::new(&NTT_PRIMES[..primes]);
+    Some(
+        (0..residues[0].len())
+            .map(|i| {
+                let value = garner.combine(residues.iter().map(|r| r[i]));
+                Integer::new(if value > modulus / 2 {
+                    value as i128 - modulus as i128
+                } else {
+                    value as i128
+                })
+            })
+            .collect(),
+    )
+}
+
+fn convolve_mod<const P: u64>(a: &[Integer], b: &[Integer]) -> Option<Vec<u64>> {
+    let reduce = |v: &[Integer]| v.iter().map(|x| ZMod::<P>::from(*x)).collect::<Vec<_>>();
+    let fa = reduce(a);
+    let product = if std::ptr::eq(a, b) {
+        ntt_convolve(&fa, &fa)?
+    } else {
+        ntt_convolve(&fa, &reduce(b))?
+    };
+    Some(product.iter().map(ZMod::value).collect())
+}
+
+/// Garner's algorithm for the [Chinese remainder theorem](https://en.wikipedia.org/wiki/Chinese_remainder_theorem),
+/// which combines residues modulo small primes via a [mixed-radix](https://en.wikipedia.org/wiki/Mixed_radix) representation.
+struct Garner<'a> {
+    primes: &'a [u64],
+    /// The inverse of `p₀·…·pᵢ₋₁` modulo `pᵢ`
+    inverses: Vec<u64>,
+}
+
+impl<'a> Garner<'a> {
+    fn new(primes: &'a [u64]) -> Self {
+        let inverses = primes
+            .iter()
+            .enumerate()
+            .map(|(i, &p)| {
+                let prefix = primes[..i]
+                    .iter()
+                    .fold(1, |acc, &q| acc * q as u128 % p as u128);
+                pow_mod(prefix as u64, p - 2, p)
+            })
+            .collect();
+        Garner { primes, inverses }
+    }
+
+    /// Returns the unique `x < p₀·…·pₖ₋₁` with the given residues.
+    fn combine(&self, residues: impl Iterator<Item = u64>) -> u128 {
+        let mut digits: Vec<u64> = Vec::with_capacity(self.primes.len());
+        for (i, r) in residues.enumerate() {
+            let p = self.primes[i] as u128;
+            // Evaluate the mixed-radix representation of the previous digits modulo p
+            let (mut value, mut radix) = (0u128, 1u128);
+            for (d, q) in digits.iter().zip(self.primes) {
+                value = (value + *d as u128 * radix) % p;
+                radix = radix * *q as u128 % p;
+            }
+            digits.push(((r as u128 + p - value) % p * self.inverses[i] as u128 % p) as u64);
+        }
+        let mut radix = 1u128;
+        let mut value = 0u128;
+        for (d, q) in digits.iter().zip(self.primes) {
+            value += *d as u128 * radix;
+            radix *= *q as u128;
+        }
+        value
+    }
+}
+
+/// Returns a primitive `size`-th root of unity modulo the prime `N`, where `size` is a power of two dividing `N - 1`.
+fn root_of_unity<const N: u64>(size: usize) -> ZMod<N> {
+    // Any quadratic non-residue z generates the 2-Sylow subgroup via z^((N - 1) / 2^s)
+    let non_residue = (2..)
+        .map(ZMod::<N>::new)
+        .find(|z| z.pow((N - 1) / 2) == -ZMod::one())
+        .unwrap();
+    non_residue.pow((N - 1) / size as u64)
+}
+
+/// The in-place iterative [Cooley-Tukey transform](https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm)
+/// with the primitive `values.len()`-th root of unity `root`.
+fn ntt<const N: u64>(values: &mut [ZMod<N>], root: ZMod<N>) {
+    let n = values.len();
+    let mut twiddles = Vec::with_capacity(n / 2);
+    let mut w = ZMod::one();
+    for _ in 0..n / 2 {
+        twiddles.push(w);
+        w *= root;
+    }
+    bit_reverse_permutation(values);
+    let mut len = 2;
+    while len <= n {
+        let stride = n / len;
+        for chunk in values.chunks_mut(len) {
+            let (lower, upper) = chunk.split_at_mut(len / 2);
+            for (j, (u, v)) in lower.iter_mut().zip(upper).enumerate() {
+                let t = *v * twiddles[j * stride];
+                *v = *u - t;
+                *u += t;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Reorders a slice of power-of-two length, such that each element is moved to its bit-reversed index.
+pub(crate) fn bit_reverse_permutation<T>(values: &mut [T]) {
+    let n = values.len();
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+fn pow_mod(base: u64, exp: u64, modulus: u64) -> u64 {
+    let (mut base, mut exp, mut result) = (base as u128 % modulus as u128, exp, 1u128);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus as u128;
+        }
+        base = base * base % modulus as u128;
+        exp >>= 1;
+    }
+    result as u64
+}
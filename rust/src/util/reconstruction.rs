@@ -0,0 +1,211 @@
+use super::{crt, gcd, half_extended_gcd, CrtErr};
+use crate::traits::*;
+use crate::types::{Integer, Natural, Rational};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReconstructionErr {
+    /// The modulus has to be positive.
+    InvalidModulus,
+    /// The bounds `N` and `D` have to satisfy `N >= 0`, `D >= 1` and `2 * N * D < m`,
+    /// as otherwise the reconstructed fraction would not be unique.
+    InvalidBounds,
+    /// There is no fraction within the bounds, that is congruent to the residue.
+    NoSolution,
+    /// The combined modulus of all images doesn't fit into an Integer.
+    ModulusOverflow,
+    /// The images could not be combined via the Chinese Remainder Theorem.
+    Crt(CrtErr),
+}
+
+impl From<CrtErr> for ReconstructionErr {
+    fn from(value: CrtErr) -> Self {
+        ReconstructionErr::Crt(value)
+    }
+}
+
+/// Finds the fraction `n / d` with `n / d ≡ a (mod m)` and `|n|, d <= ⌊√((m - 1) / 2)⌋`, if it exists.
+///
+/// These bounds are the largest balanced ones, for which the fraction is unique, i.e. the largest `B` with `2B² < m`.
+/// See [`rational_reconstruction_with_bounds`] for details.
+pub fn rational_reconstruction(a: Integer, m: Integer) -> Result<Rational, ReconstructionErr> {
+    if !m.is_positive() {
+        return Err(ReconstructionErr::InvalidModulus);
+    }
+    let bound = Integer::new(isqrt_u128((m.value() as u128 - 1) / 2) as i128);
+    rational_reconstruction_with_bounds(a, m, bound, bound)
+}
+
+/// [Rational reconstruction](https://en.wikipedia.org/wiki/Rational_reconstruction_(mathematics)):
+/// Finds the fraction `n / d` with `n / d ≡ a (mod m)`, `|n| <= num_bound` and `0 < d <= den_bound`, if it exists.
+///
+/// If `2 * num_bound * den_bound < m` there is at most one such fraction; otherwise [`ReconstructionErr::InvalidBounds`]
+/// is returned. It is found via the half-extended euclidean algorithm on `m` and `a`, which is stopped
+/// at the first remainder `n <= num_bound` (see [`half_extended_gcd`]). If the corresponding cofactor `d`
+/// exceeds `den_bound` or isn't coprime to `n`, there is no solution.
+pub fn rational_reconstruction_with_bounds(
+    a: Integer,
+    m: Integer,
+    num_bound: Integer,
+    den_bound: Integer,
+) -> Result<Rational, ReconstructionErr> {
+    if !m.is_positive() {
+        return Err(ReconstructionErr::InvalidModulus);
+    }
+    if num_bound.is_negative() || !den_bound.is_positive() {
+        return Err(ReconstructionErr::InvalidBounds);
+    }
+    match num_bound.value().checked_mul(2 * den_bound.value()) {
+        Some(product) if product < m.value() => {}
+        _ => return Err(ReconstructionErr::InvalidBounds),
+    }
+
+    let (numer, denom) = half_extended_gcd(m, a % m, num_bound.value() as u128);
+    if denom.is_zero() || denom.abs() > den_bound || !gcd(numer, denom).is_one() {
+        return Err(ReconstructionErr::NoSolution);
+    }
+    Ok(Rational::new(numer, denom))
+}
+
+/// Combines the images of a vector of rationals modulo several coprime moduli,
+/// to reconstruct the rationals via the Chinese Remainder Theorem and rational reconstruction.
+///
+/// This is the bookkeeping part of multi-modular algorithms, which compute a result modulo many primes
+/// instead of computing it over *ℚ* directly. See [`lift_rationals`] for a complete driver.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiModular {
+    residues: Vec<Integer>,
+    modulus: Integer,
+}
+
+impl MultiModular {
+    pub fn new() -> Self {
+        MultiModular {
+            residues: Vec::new(),
+            modulus: Integer::one(),
+        }
+    }
+
+    /// The combined residues of all images added so far.
+    pub fn residues(&self) -> &Vec<Integer> {
+        &self.residues
+    }
+
+    /// The product of the moduli of all images added so far.
+    pub fn modulus(&self) -> Integer {
+        self.modulus
+    }
+
+    /// Adds the image `residues (mod modulus)`.
+    ///
+    /// The new modulus has to be coprime to the previous ones and the combined modulus has to fit into an Integer.
+    /// In case of an error, nothing is changed.
+    ///
+    /// Panics if the number of residues differs from the previous images.
+    pub fn add_image(
+        &mut self,
+        residues: &[Integer],
+        modulus: Integer,
+    ) -> Result<(), ReconstructionErr> {
+        let previous = if self.modulus.is_one() {
+            vec![Integer::zero(); residues.len()]
+        } else if self.residues.len() == residues.len() {
+            self.residues.clone()
+        } else {
+            panic!("All images have to contain the same number of residues")
+        };
+        if modulus.is_zero() {
+            return Err(CrtErr::ZeroModulus.into());
+        }
+        // Leave some room for the intermediate results of the Chinese Remainder Theorem
+        let combined_modulus = match self.modulus.value().checked_mul(modulus.abs().value()) {
+            Some(product) if product <= i128::MAX / 2 => Integer::new(product),
+            _ => return Err(ReconstructionErr::ModulusOverflow),
+        };
+
+        self.residues = previous
+            .into_iter()
+            .zip(residues)
+            .map(|(a, b)| crt(&[(a, self.modulus), (*b, modulus)]).map(|(x, _)| x))
+            .collect::<Result<_, _>>()?;
+        self.modulus = combined_modulus;
+        Ok(())
+    }
+
+    /// Reconstructs the rationals from the combined residues via [`rational_reconstruction`].
+    pub fn reconstruct(&self) -> Result<Vec<Rational>, ReconstructionErr> {
+        self.residues
+            .iter()
+            .map(|a| rational_reconstruction(*a, self.modulus))
+            .collect()
+    }
+
+    /// Reconstructs integers from the combined residues by choosing the representatives `-m/2 < a <= m/2`.
+    pub fn reconstruct_integers(&self) -> Vec<Integer> {
+        let half = self.modulus / Integer::new(2);
+        self.residues
+            .iter()
+            .map(|a| if *a > half { *a - self.modulus } else { *a })
+            .collect()
+    }
+}
+
+impl Default for MultiModular {
+    fn default() -> Self {
+        MultiModular::new()
+    }
+}
+
+/// Drives a multi-modular computation of a vector of rationals.
+///
+/// For each of the given `primes`, `image` computes the result modulo this prime, or returns `None`
+/// if the prime is unlucky (e.g. divides a denominator) and should be skipped.
+/// After each image, the rationals are reconstructed; once the reconstruction yields the same result twice in a row,
+/// it is returned. This early termination is a heuristic: the result is only proven to be correct
+/// if the moduli are large enough for known bounds on the numerators and denominators.
+///
+/// If the primes are exhausted before that, the last reconstruction error is returned.
+pub fn lift_rationals<I, F>(primes: I, mut image: F) -> Result<Vec<Rational>, ReconstructionErr>
+where
+    I: IntoIterator<Item = Natural>,
+    F: FnMut(Natural) -> Option<Vec<Integer>>,
+{
+    let mut images = MultiModular::new();
+    let mut last = Err(ReconstructionErr::NoSolution);
+    for p in primes {
+        let Some(residues) = image(p) else {
+            continue;
+        };
+        images.add_image(&residues, Integer::from(p))?;
+        let current = images.reconstruct();
+        if current.is_ok() && current == last {
+            return current;
+        }
+        last = current;
+    }
+    last
+}
+
+/// Drives a multi-modular computation of a single rational. See [`lift_rationals`].
+pub fn lift_rational<I, F>(primes: I, mut image: F) -> Result<Rational, ReconstructionErr>
+where
+    I: IntoIterator<Item = Natural>,
+    F: FnMut(Natural) -> Option<Integer>,
+{
+    lift_rationals(primes, |p| image(p).map(|a| vec![a])).map(|result| result[0])
+}
+
+/// Returns `⌊√n⌋`.
+fn isqrt_u128(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's iteration, starting above the root
+    let mut x = 1u128 << (128 - n.leading_zeros()).div_ceil(2);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
@@ -0,0 +1,113 @@
+use polydiv::number_theory::*;
+use polydiv::types::*;
+use polydiv::util::gcd;
+
+fn nat(n: u64) -> Natural {
+    Natural::new(n)
+}
+
+fn naive_divisors(n: u64) -> Vec<u64> {
+    (1..=n).filter(|d| n.is_multiple_of(*d)).collect()
+}
+
+fn multiplicative_order(a: u64, n: u64) -> u64 {
+    let mut x = a % n;
+    let mut k = 1;
+    while x != 1 % n {
+        x = x * a % n;
+        k += 1;
+    }
+    k
+}
+
+#[test]
+fn euler_phi_values() {
+    for n in 1..500u64 {
+        let coprime = (1..=n)
+            .filter(|k| gcd(Integer::from(*k), Integer::from(n)) == Integer::new(1))
+            .count() as u64;
+        assert_eq!(euler_phi(nat(n)), nat(coprime), "{n}");
+    }
+    assert_eq!(euler_phi(nat(1_000_000_007)), nat(1_000_000_006));
+}
+
+#[test]
+fn mobius_values() {
+    let expected = [1, -1, -1, 0, -1, 1, -1, 0, 0, 1, -1, 0, -1, 1, 1, 0];
+    for (n, mu) in (1..).zip(expected) {
+        assert_eq!(mobius(nat(n)), mu, "{n}");
+    }
+    // The Möbius function sums to zero over the divisors of every n > 1
+    for n in 2..300 {
+        let sum: i64 = divisors(nat(n)).iter().map(|d| mobius(*d) as i64).sum();
+        assert_eq!(sum, 0, "{n}");
+    }
+}
+
+#[test]
+fn divisor_functions() {
+    for n in 1..300u64 {
+        let naive = naive_divisors(n);
+        let divisors: Vec<u64> = divisors(nat(n)).iter().map(|d| d.value()).collect();
+        assert_eq!(divisors, naive, "{n}");
+        assert_eq!(sigma_k(nat(n), 0), Some(nat(naive.len() as u64)));
+        assert_eq!(sigma_k(nat(n), 1), Some(nat(naive.iter().sum())));
+        assert_eq!(
+            sigma_k(nat(n), 2),
+            Some(nat(naive.iter().map(|d| d * d).sum()))
+        );
+    }
+    assert_eq!(sigma_k(nat(28), 1), Some(nat(56)));
+}
+
+#[test]
+fn overflow() {
+    // σ₃(10⁷) ≈ 1.15 · 10²¹ doesn't fit into 64 bits
+    assert_eq!(sigma_k(nat(10_000_000), 3), None);
+    assert_eq!(sigma_k(nat(10_000_000), 2), Some(nat(138_886_769_611_720)));
+    assert_eq!(sigma_k(nat(2), 64), None);
+    assert_eq!(sigma_k(nat(2), 63), Some(nat((1 << 63) + 1)));
+
+    // The largest 64-bit prime
+    let p = u64::MAX - 58;
+    assert_eq!(euler_phi(nat(p)), nat(p - 1));
+    assert_eq!(carmichael_lambda(nat(p)), nat(p - 1));
+    assert_eq!(divisors(nat(p)), vec![nat(1), nat(p)]);
+    assert_eq!(sigma_k(nat(p), 1), Some(nat(p + 1)));
+    assert_eq!(sigma_k(nat(u64::MAX), 1), None);
+    assert_eq!(sigma_k(nat(p), 0), Some(nat(2)));
+    // 2⁶⁴ - 1 = 3 · 5 · 17 · 257 · 641 · 65537 · 6700417
+    assert_eq!(divisors(nat(u64::MAX)).len(), 128);
+
+    // Factorizations can describe numbers beyond the range of a Natural
+    let mut big = Factorization::new();
+    big.push(nat(p), 1);
+    big.push(nat(3), 1);
+    assert_eq!(big.euler_phi(), None);
+    assert_eq!(big.divisors(), None);
+    assert_eq!(big.carmichael_lambda(), Some(nat(p - 1)));
+    big.push(nat(1_000_000_007), 1);
+    assert_eq!(big.carmichael_lambda(), None);
+}
+
+#[test]
+fn carmichael_lambda_values() {
+    for n in 2..300u64 {
+        let units: Vec<u64> = (1..n)
+            .filter(|k| gcd(Integer::from(*k), Integer::from(n)) == Integer::new(1))
+            .collect();
+        let exponent = units
+            .iter()
+            .map(|a| multiplicative_order(*a, n))
+            .fold(Integer::new(1), |acc, k| {
+                polydiv::util::lcm(acc, Integer::from(k))
+            });
+        assert_eq!(
+            carmichael_lambda(nat(n)),
+            nat(exponent.value() as u64),
+            "{n}"
+        );
+    }
+    assert_eq!(carmichael_lambda(nat(1)), nat(1));
+    assert_eq!(carmichael_lambda(nat(561)), nat(80));
+}
@@ -0,0 +1,83 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+
+fn rat(n: i128, d: i128) -> Rational {
+    Rational::new(Integer::new(n), Integer::new(d))
+}
+
+fn c(re: Rational, im: Rational) -> Complex<Rational> {
+    Complex::new(re, im)
+}
+
+#[test]
+fn gaussian_rationals() {
+    type C = Complex<Rational>;
+    let i = C::i();
+    assert_eq!(i * i, -C::one());
+    let a = c(rat(1, 1), rat(1, 1));
+    let b = c(rat(1, 1), rat(-1, 1));
+    assert_eq!(a / b, i);
+    assert_eq!(a * b, C::from(rat(2, 1)));
+    assert_eq!(a.norm(), rat(2, 1));
+    assert_eq!(a.conjugate(), b);
+
+    let z = c(rat(3, 4), rat(-2, 5));
+    assert_eq!(z * z.multiplicative_inverse(), C::one());
+    assert_eq!((a / z) * z, a);
+    assert_eq!(C::zero().inverse(), None);
+    assert!(C::is_field());
+
+    assert_eq!(z.to_string(), "3/4 + -2/5i");
+    assert_eq!(i.to_string(), "i");
+    assert_eq!(C::from(rat(5, 1)).to_string(), "5");
+}
+
+#[test]
+fn finite_field_with_p_squared_elements() {
+    // x² + 1 is irreducible over GF(3), so Complex<ZMod<3>> is GF(9)
+    type F = ZMod<3>;
+    type G = Complex<F>;
+    assert!(G::is_field());
+    let elements: Vec<G> = (0..9)
+        .map(|n| G::new(F::new(n % 3), F::new(n / 3)))
+        .collect();
+    for x in &elements[1..] {
+        assert_eq!(*x * x.multiplicative_inverse(), G::one());
+        assert_eq!(*x / *x, G::one());
+    }
+    // 1 + i generates the multiplicative group of order 8
+    let generator = G::new(F::new(1), F::new(1));
+    let powers: Vec<G> = (1..=8)
+        .scan(G::one(), |acc, _| {
+            *acc *= generator;
+            Some(*acc)
+        })
+        .collect();
+    assert_eq!(powers[7], G::one());
+    assert!(powers[..7].iter().all(|x| !x.is_one()));
+
+    // The same for a larger prime p = 3 (mod 4)
+    type H = Complex<ZMod<1_000_000_007>>;
+    let x = H::new(ZMod::new(123456789), ZMod::new(987654321));
+    assert_eq!(x * x.multiplicative_inverse(), H::one());
+
+    // Any prime p = 3 (mod 4) works, e.g. GF(10007²)
+    type K = Complex<ZMod<10007>>;
+    assert!(K::is_field());
+    let y = K::new(ZMod::new(17), ZMod::new(4242));
+    assert_eq!(y / y, K::one());
+}
+
+#[test]
+fn quotient_ring() {
+    // Over GF(5) -1 = 2² is a square, so Complex<ZMod<5>> ≅ GF(5) × GF(5) has zero divisors
+    type F = ZMod<5>;
+    type R = Complex<F>;
+    assert!(!R::is_field());
+    let a = R::new(F::new(2), F::new(1));
+    let b = R::new(F::new(2), F::new(4));
+    assert!((a * b).is_zero());
+    assert_eq!(a.inverse(), None);
+    let u = R::new(F::new(1), F::new(1));
+    assert_eq!(u * u.inverse().unwrap(), R::one());
+}
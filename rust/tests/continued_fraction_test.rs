@@ -0,0 +1,136 @@
+use polydiv::types::*;
+
+fn int(n: i128) -> Integer {
+    Integer::new(n)
+}
+
+fn rat(n: i128, d: i128) -> Rational {
+    Rational::new(int(n), int(d))
+}
+
+fn ints(values: &[i128]) -> Vec<Integer> {
+    values.iter().map(|v| int(*v)).collect()
+}
+
+#[test]
+fn to_and_from_continued_fractions() {
+    assert_eq!(rat(415, 93).continued_fraction(), ints(&[4, 2, 6, 7]));
+    assert_eq!(rat(-415, 93).continued_fraction(), ints(&[-5, 1, 1, 6, 7]));
+    assert_eq!(rat(3, 1).continued_fraction(), ints(&[3]));
+    assert_eq!(rat(1, 2).continued_fraction(), ints(&[0, 2]));
+    assert_eq!(rat(0, 1).continued_fraction(), ints(&[0]));
+
+    for r in [
+        rat(415, 93),
+        rat(-415, 93),
+        rat(355, 113),
+        rat(0, 1),
+        rat(-7, 1),
+    ] {
+        assert_eq!(
+            Rational::from_continued_fraction(&r.continued_fraction()),
+            r
+        );
+    }
+    // Non-canonical forms evaluate to the same number
+    assert_eq!(
+        Rational::from_continued_fraction(&ints(&[4, 2, 6, 6, 1])),
+        rat(415, 93)
+    );
+}
+
+#[test]
+#[should_panic]
+fn empty_continued_fraction() {
+    Rational::from_continued_fraction(&[]);
+}
+
+#[test]
+fn convergents() {
+    assert_eq!(
+        rat(415, 93).convergents(),
+        vec![rat(4, 1), rat(9, 2), rat(58, 13), rat(415, 93)]
+    );
+    // 3.14159265358979 = [3; 7, 15, 1, 292, ...]
+    let pi = Rational::try_from(std::f64::consts::PI).unwrap();
+    assert_eq!(
+        pi.convergents()[..5],
+        [
+            rat(3, 1),
+            rat(22, 7),
+            rat(333, 106),
+            rat(355, 113),
+            rat(103993, 33102)
+        ]
+    );
+}
+
+#[test]
+fn semiconvergents() {
+    let semiconvergents: Vec<_> = rat(415, 93).semiconvergents().collect();
+    assert_eq!(
+        semiconvergents,
+        vec![
+            rat(4, 1),
+            rat(5, 1),
+            rat(9, 2),
+            rat(13, 3),
+            rat(22, 5),
+            rat(31, 7),
+            rat(40, 9),
+            rat(49, 11),
+            rat(58, 13),
+            rat(67, 15),
+            rat(125, 28),
+            rat(183, 41),
+            rat(241, 54),
+            rat(299, 67),
+            rat(357, 80),
+            rat(415, 93),
+        ]
+    );
+    assert_eq!(
+        rat(1, 1_000_000_000_000).semiconvergents().nth(3),
+        Some(rat(1, 3))
+    );
+}
+
+#[test]
+fn best_approximations() {
+    let pi = Rational::try_from(std::f64::consts::PI).unwrap();
+    assert_eq!(pi.best_approximation(int(1)), rat(3, 1));
+    assert_eq!(pi.best_approximation(int(7)), rat(22, 7));
+    assert_eq!(pi.best_approximation(int(100)), rat(311, 99));
+    assert_eq!(pi.best_approximation(int(113)), rat(355, 113));
+    assert_eq!(pi.best_approximation(int(30000)), rat(94053, 29938));
+
+    // Compare with a brute force search
+    let x = rat(415, 93);
+    for bound in 1..=93 {
+        let best = (1..=bound)
+            .map(|d| rat((x * rat(d, 1)).round().value(), d))
+            .min_by(|a, b| (*a - x).abs().cmp(&(*b - x).abs()))
+            .unwrap();
+        assert_eq!(x.best_approximation(int(bound)), best);
+    }
+    assert_eq!(rat(-1, 3).best_approximation(int(2)), rat(-1, 2));
+    assert_eq!(rat(-1, 3).best_approximation(int(1_000)), rat(-1, 3));
+}
+
+#[test]
+fn float_approximations() {
+    assert_eq!(
+        Rational::approximate_f64(1.0 / 3.0, int(1000)),
+        Ok(rat(1, 3))
+    );
+    assert_eq!(
+        Rational::approximate_f64(-0.142857, int(10)),
+        Ok(rat(-1, 7))
+    );
+    assert_eq!(Rational::approximate_f64(0.1, int(1 << 20)), Ok(rat(1, 10)));
+    assert_eq!(Rational::approximate_f64(2.5, int(1)), Ok(rat(2, 1)));
+    assert_eq!(
+        Rational::approximate_f64(f64::NAN, int(10)),
+        Err(FromFloatErr::NotFinite)
+    );
+}
@@ -0,0 +1,64 @@
+use polydiv::types::*;
+use polydiv::util::*;
+
+fn int(n: i128) -> Integer {
+    Integer::new(n)
+}
+
+fn congruences(pairs: &[(i128, i128)]) -> Vec<(Integer, Integer)> {
+    pairs.iter().map(|&(a, m)| (int(a), int(m))).collect()
+}
+
+#[test]
+fn coprime_moduli() {
+    assert_eq!(
+        crt(&congruences(&[(2, 3), (3, 5), (2, 7)])),
+        Ok((int(23), int(105)))
+    );
+    assert_eq!(
+        crt(&congruences(&[(-1, 4), (0, 9)])),
+        Ok((int(27), int(36)))
+    );
+    assert_eq!(crt(&congruences(&[(5, 7)])), Ok((int(5), int(7))));
+    assert_eq!(crt::<Integer>(&[]), Ok((int(0), int(1))));
+    assert_eq!(
+        crt(&congruences(&[(1, -5), (2, 3)])),
+        Ok((int(11), int(15)))
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        crt(&congruences(&[(1, 4), (3, 6)])),
+        Err(CrtErr::NotCoprime)
+    );
+    assert_eq!(
+        crt(&congruences(&[(1, 4), (3, 0)])),
+        Err(CrtErr::ZeroModulus)
+    );
+    assert_eq!(
+        crt_general(&congruences(&[(1, 4), (2, 6)])),
+        Err(CrtErr::Inconsistent)
+    );
+    assert_eq!(
+        CrtErr::NotCoprime.to_string(),
+        "the moduli are not pairwise coprime"
+    );
+}
+
+#[test]
+fn non_coprime_moduli() {
+    assert_eq!(
+        crt_general(&congruences(&[(1, 4), (3, 6)])),
+        Ok((int(9), int(12)))
+    );
+    assert_eq!(
+        crt_general(&congruences(&[(3, 10), (8, 15), (5, 7)])),
+        Ok((int(173), int(210)))
+    );
+    assert_eq!(
+        crt_general(&congruences(&[(2, 3), (3, 5), (2, 7)])),
+        Ok((int(23), int(105)))
+    );
+}
@@ -0,0 +1,90 @@
+use polydiv::number_theory::*;
+use polydiv::traits::*;
+use polydiv::types::*;
+
+fn nat(n: u64) -> Natural {
+    Natural::new(n)
+}
+
+fn unit<const N: u64>(value: u64) -> Unit<ZMod<N>> {
+    Unit::try_from(ZMod::new(value)).unwrap()
+}
+
+#[test]
+fn unit_group() {
+    type G = Unit<ZMod<15>>;
+    assert!(G::try_from(ZMod::new(5)).is_err());
+    let g = unit::<15>(2);
+    assert!(matches!(g.size(), SetSize::Finite(8)));
+    assert_eq!(g.pow(4), g.identity());
+    assert_eq!(g.op(&g.inverse()), g.identity());
+    assert_eq!(g.domain().to_string(), "(Z/15Z)^*");
+
+    let ring = Zn::new(15).unwrap();
+    assert!(Unit::try_from(ring.element(6)).is_err());
+    let h = Unit::try_from(ring.element(7)).unwrap();
+    assert!(h.contains());
+    assert_eq!(h.pow(4).value().value(), 1);
+}
+
+#[test]
+fn orders() {
+    // 3 is a primitive root modulo 998244353 = 119 * 2^23 + 1
+    let group_order = factor(nat(998244352));
+    let g = unit::<998244353>(3);
+    assert_eq!(element_order(&g, &group_order), group_order);
+    assert_eq!(
+        element_order(&g.pow(119), &group_order).product(),
+        nat(1 << 23)
+    );
+    assert_eq!(element_order(&g.identity(), &group_order).product(), nat(1));
+}
+
+#[test]
+fn baby_step_giant_step_small() {
+    let g = unit::<101>(2);
+    for x in 0..100 {
+        let target = g.pow(x);
+        assert_eq!(baby_step_giant_step(&g, &target, nat(100)), Some(nat(x)));
+        assert_eq!(discrete_log(&g, &target), Some(nat(x)));
+    }
+    // 4 only generates the squares
+    let g = unit::<101>(4);
+    assert_eq!(baby_step_giant_step(&g, &unit(2), nat(100)), None);
+    assert_eq!(discrete_log(&g, &unit(2)), None);
+    assert_eq!(discrete_log(&g, &unit(16)), Some(nat(2)));
+}
+
+#[test]
+fn pohlig_hellman_smooth_order() {
+    type F = ZMod<998244353>;
+    let g = Unit::try_from(F::new(3)).unwrap();
+    let order = factor(nat(998244352));
+    for x in [0, 1, 12345, 998244351, 1 << 23] {
+        let target = g.pow(x);
+        assert_eq!(pohlig_hellman(&g, &target, &order), Some(nat(x)));
+    }
+
+    // The same with a runtime modulus
+    let ring = Zn::new(998244353).unwrap();
+    let g = Unit::try_from(ring.element(3)).unwrap();
+    let target = Unit::try_from(ring.element(123456789)).unwrap();
+    let x = discrete_log(&g, &target).unwrap();
+    assert_eq!(g.pow(x.value()), target);
+}
+
+#[test]
+fn pollard_rho_prime_order() {
+    // p = 2q + 1 is a safe prime, so the squares form a subgroup of prime order q
+    let (q, p) = (17179869659, 34359739319);
+    let ring = Zn::new(p).unwrap();
+    let g = Unit::try_from(ring.element(4)).unwrap();
+    for x in [5, 9876543210, q - 1] {
+        let target = g.pow(x);
+        assert_eq!(pollard_rho_log(&g, &target, nat(q)), Some(nat(x)));
+    }
+    let target = g.pow(1234567890);
+    assert_eq!(discrete_log(&g, &target), Some(nat(1234567890)));
+    let non_square = Unit::try_from(ring.element(p - 1)).unwrap();
+    assert_eq!(discrete_log(&g, &non_square), None);
+}
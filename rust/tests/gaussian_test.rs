@@ -0,0 +1,97 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+fn g(re: i64, im: i64) -> GaussianInteger {
+    GaussianInteger::from((re, im))
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(g(1, 2) * g(3, -1), g(5, 5));
+    assert_eq!(g(1, 2) + g(3, -1), g(4, 1));
+    assert_eq!(
+        GaussianInteger::i() * GaussianInteger::i(),
+        -GaussianInteger::one()
+    );
+    assert_eq!(g(3, 4).norm(), Integer::new(25));
+    assert_eq!(g(3, 4).conjugate(), g(3, -4));
+    assert_eq!(g(0, 1).inverse(), Some(g(0, -1)));
+    assert_eq!(g(1, 1).inverse(), None);
+
+    assert_eq!(g(3, -4).to_string(), "3 - 4i");
+    assert_eq!(g(3, 1).to_string(), "3 + i");
+    assert_eq!(g(0, -1).to_string(), "-i");
+    assert_eq!(g(-2, 0).to_string(), "-2");
+}
+
+#[test]
+fn euclidean_division() {
+    for a in [g(27, 23), g(-5, 17), g(0, 0), g(100, -3), g(1, 1)] {
+        for b in [g(8, 1), g(-3, -3), g(0, 5), g(2, 0), g(1, 1)] {
+            let (q, r) = a.div_rem(&b);
+            assert_eq!(q * b + r, a);
+            assert!(r.euclidean_value() * 2 <= b.euclidean_value());
+        }
+    }
+    assert_eq!(g(27, 23) / g(8, 1), g(4, 2));
+    assert_eq!(g(27, 23) % g(8, 1), g(-3, 3));
+
+    assert_eq!(g(-3, 2).unit_normal(), g(2, 3));
+    assert_eq!(g(-3, -2).unit_normal(), g(3, 2));
+    assert_eq!(g(3, -2).unit_normal(), g(2, 3));
+    assert_eq!(g(0, 5).unit_normal(), g(5, 0));
+}
+
+#[test]
+fn gcds() {
+    // N(11 + 3i) = 2 * 5 * 13 and N(1 + 8i) = 5 * 13, but they only share the prime 2 + i
+    let a = g(11, 3);
+    let b = g(1, 8);
+    let d = gcd(a, b);
+    assert_eq!(d, g(2, 1));
+    assert!(d.divides(&a) && d.divides(&b));
+
+    let ExtendedGcd { gcd: d, x, y } = extended_gcd(a, b);
+    assert_eq!(x * a + y * b, d);
+    assert_eq!(d, g(2, 1));
+
+    let p = g(2, 1) * g(3, 2);
+    assert_eq!(gcd(p * g(1, 4), p * g(7, 0)), p.unit_normal());
+    assert_eq!(lcm(g(1, 1), g(2, 0)), g(2, 0));
+
+    // The generic Chinese Remainder Theorem
+    let (x, m) = crt(&[(g(1, 0), g(2, 1)), (g(0, 1), g(3, 0))]).unwrap();
+    assert_eq!(m, g(6, 3));
+    assert!(g(2, 1).divides(&(x - g(1, 0))));
+    assert!(g(3, 0).divides(&(x - g(0, 1))));
+}
+
+#[test]
+fn factorization() {
+    assert!(g(3, 0).is_prime());
+    assert!(!g(5, 0).is_prime());
+    assert!(g(2, 1).is_prime());
+    assert!(g(0, 7).is_prime());
+    assert!(!g(1, 0).is_prime());
+
+    let f = g(2, 0).factor();
+    assert_eq!(f.factors(), &vec![(g(1, 1), 2)]);
+    assert_eq!(f.unit(), Some(&g(0, -1)));
+
+    for x in [
+        g(5, 0),
+        g(-360, 0),
+        g(27, 23),
+        g(0, -1),
+        g(1234, 5678),
+        g(1, 1),
+        g(99991, 0),
+    ] {
+        let f = x.factor();
+        assert_eq!(f.product(), x);
+        assert!(f.primes().all(|p| p.is_prime() && *p == p.unit_normal()));
+    }
+    assert_eq!(g(5, 0).factor().to_string(), "-i * 1 + 2i * 2 + i");
+    assert_eq!(g(1234, 5678).factor().count(), 5);
+}
@@ -0,0 +1,54 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+fn int(n: i128) -> Integer {
+    Integer::new(n)
+}
+
+#[test]
+fn gcd_integers() {
+    assert_eq!(gcd(int(12), int(18)), int(6));
+    assert_eq!(gcd(int(-12), int(18)), int(6));
+    assert_eq!(gcd(int(12), int(-18)), int(6));
+    assert_eq!(gcd(int(0), int(-7)), int(7));
+    assert_eq!(gcd(int(0), int(0)), int(0));
+    assert_eq!(gcd(int(17), int(5)), int(1));
+}
+
+#[test]
+fn extended_gcd_integers() {
+    for (a, b) in [(240, 46), (-240, 46), (46, -240), (0, 5), (7, 0), (13, 13)] {
+        let ExtendedGcd { gcd: g, x, y } = extended_gcd(int(a), int(b));
+        assert_eq!(g, gcd(int(a), int(b)));
+        assert_eq!(x * int(a) + y * int(b), g);
+    }
+}
+
+#[test]
+fn lcm_integers() {
+    assert_eq!(lcm(int(4), int(6)), int(12));
+    assert_eq!(lcm(int(-4), int(6)), int(12));
+    assert_eq!(lcm(int(0), int(6)), int(0));
+    assert!(int(4).divides(&int(12)));
+    assert!(!int(5).divides(&int(12)));
+}
+
+#[test]
+fn gcd_of_many() {
+    assert_eq!(gcd_all([12, -18, 30].map(int)), int(6));
+    assert_eq!(gcd_all([0, 0].map(int)), int(0));
+    assert_eq!(gcd_all(Vec::<Integer>::new()), int(0));
+    assert_eq!(lcm_all([4, -6, 10].map(int)), int(60));
+    assert_eq!(lcm_all([4, 0].map(int)), int(0));
+    assert_eq!(lcm_all(Vec::<Integer>::new()), int(1));
+}
+
+#[test]
+fn inverse_mod_integers() {
+    let x = inverse_mod(int(3), int(7)).unwrap();
+    assert_eq!((x * int(3)).div_rem(&int(7)).1.value().rem_euclid(7), 1);
+    assert_eq!(inverse_mod(int(4), int(6)), None);
+    assert_eq!(inverse_mod(int(-1), int(0)), Some(int(-1)));
+    assert_eq!(inverse_mod(int(2), int(0)), None);
+}
@@ -0,0 +1,117 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+
+type L = LaurentPolynomial<Rational>;
+type P = Polynomial<Rational>;
+
+fn r(n: i128) -> Rational {
+    Rational::from(Integer::new(n))
+}
+
+fn l(coefs: &[i128], valuation: isize) -> L {
+    LaurentPolynomial::from_vec(coefs.iter().map(|c| r(*c)).collect(), valuation)
+}
+
+fn p(coefs: &[i128]) -> P {
+    Polynomial::from_vec(coefs.iter().map(|c| r(*c)).collect())
+}
+
+#[test]
+fn normalization() {
+    let a = l(&[0, 0, 3, 0, 2, 0], -4);
+    assert_eq!(a, l(&[3, 0, 2], -2));
+    assert_eq!(a.valuation(), Some(-2));
+    assert_eq!(a.degree(), Some(0));
+    assert_eq!(a.coef(-2), r(3));
+    assert_eq!(a.coef(-1), r(0));
+    assert_eq!(a.coef(5), r(0));
+    assert_eq!(l(&[0, 0], 3), L::zero());
+    assert_eq!(L::zero().valuation(), None);
+    assert_eq!(L::monomial(r(7), -3).valuation(), Some(-3));
+}
+
+#[test]
+fn display() {
+    assert_eq!(L::zero().to_string(), "0");
+    assert_eq!(l(&[7, 2, 1, 0, 1], -1).to_string(), "x^3 + x + 2 + 7x^(-1)");
+    assert_eq!(l(&[-37, -4], -16).to_string(), "-4x^(-15) + -37x^(-16)");
+}
+
+#[test]
+fn ring_ops() {
+    let a = l(&[1, 2], -1);
+    let b = l(&[3, 0, 1], 0);
+    assert_eq!(&a + &b, l(&[1, 5, 0, 1], -1));
+    assert_eq!(&a - &a, L::zero());
+    assert_eq!(&b - &a, l(&[-1, 1, 0, 1], -1));
+    assert_eq!(-&a, l(&[-1, -2], -1));
+    assert_eq!(&a * &b, l(&[3, 6, 1, 2], -1));
+    assert_eq!(L::x() * L::monomial(r(1), -1), L::one());
+
+    let mut c = l(&[1, 1], 0);
+    c.shift(-5);
+    assert_eq!(c, l(&[1, 1], -5));
+    c.shift(5);
+    assert_eq!(c, l(&[1, 1], 0));
+
+    // x + x⁻¹ at 2
+    assert_eq!(
+        l(&[1, 0, 1], -1).eval(&r(2)),
+        Rational::new(Integer::new(5), Integer::new(2))
+    );
+}
+
+#[test]
+fn units() {
+    let a = L::monomial(r(4), -3);
+    assert_eq!(
+        a.inverse(),
+        Some(L::monomial(
+            Rational::new(Integer::new(1), Integer::new(4)),
+            3
+        ))
+    );
+    assert_eq!(l(&[1, 1], 0).inverse(), None);
+    assert_eq!(L::zero().inverse(), None);
+}
+
+#[test]
+fn polynomial_conversion() {
+    let q = p(&[0, 0, 1, 2]);
+    let a = L::from(q.clone());
+    assert_eq!(a, l(&[1, 2], 2));
+    assert!(a.is_polynomial());
+    assert_eq!(P::try_from(a), Ok(q));
+    assert_eq!(P::try_from(l(&[1], -1)), Err(LaurentErr::NegativePowers));
+    assert_eq!(P::try_from(L::zero()), Ok(P::zero()));
+}
+
+#[test]
+fn fraction_conversion() {
+    let a = l(&[5, 0, 1, 2], -2);
+    let (num, den) = a.to_fraction();
+    assert_eq!(num, p(&[5, 0, 1, 2]));
+    assert_eq!(den, p(&[0, 0, 1]));
+    assert_eq!(L::from_fraction(&num, &den), Ok(a));
+
+    let (num, den) = l(&[1, 1], 3).to_fraction();
+    assert_eq!((num, den), (p(&[0, 0, 0, 1, 1]), P::one()));
+
+    // (x² - 1) / (x³ - x²) = (x + 1) / x²
+    assert_eq!(
+        L::from_fraction(&p(&[-1, 0, 1]), &p(&[0, 0, -1, 1])),
+        Ok(l(&[1, 1], -2))
+    );
+    assert_eq!(
+        L::from_fraction(&p(&[1]), &p(&[1, 1])),
+        Err(LaurentErr::NotLaurent)
+    );
+    assert_eq!(
+        L::from_fraction(&p(&[1]), &P::zero()),
+        Err(LaurentErr::ZeroDenominator)
+    );
+    assert_eq!(
+        LaurentErr::NotLaurent.to_string(),
+        "the denominator is not a power of the variable"
+    );
+}
@@ -0,0 +1,97 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+
+type M = Matrix<Rational, 2>;
+
+fn r(n: i128) -> Rational {
+    Rational::from(Integer::new(n))
+}
+
+fn m(a: i128, b: i128, c: i128, d: i128) -> M {
+    Matrix::new([[r(a), r(b)], [r(c), r(d)]])
+}
+
+#[test]
+fn ring_ops() {
+    let a = m(1, 2, 3, 4);
+    let b = m(0, 1, 1, 0);
+    assert_eq!(&a + &b, m(1, 3, 4, 4));
+    assert_eq!(&a - &a, M::zero());
+    assert_eq!(-&a, m(-1, -2, -3, -4));
+    assert_eq!(&a * &b, m(2, 1, 4, 3));
+    assert_eq!(&b * &a, m(3, 4, 1, 2));
+    assert_eq!(&a * &M::one(), a);
+    assert_eq!(a.transpose(), m(1, 3, 2, 4));
+    assert_eq!(a.trace(), r(5));
+    assert_eq!(a[(1, 0)], r(3));
+    assert_eq!(a.to_string(), "[[1, 2], [3, 4]]");
+}
+
+#[test]
+fn determinant_and_inverse() {
+    let a = m(1, 2, 3, 4);
+    assert_eq!(a.determinant(), r(-2));
+    assert_eq!(m(0, 1, 1, 0).determinant(), r(-1));
+    assert_eq!(m(1, 2, 2, 4).determinant(), r(0));
+
+    let inv = a.inverse().unwrap();
+    assert_eq!(&a * &inv, M::one());
+    assert_eq!(&inv * &a, M::one());
+    assert_eq!(m(1, 2, 2, 4).inverse(), None);
+
+    let c: Matrix<Rational, 3> =
+        Matrix::from_fn(|i, j| r((i * 3 + j) as i128 * (i as i128 - 1) + 1));
+    assert_eq!(c.determinant(), c.transpose().determinant());
+}
+
+#[test]
+fn integer_matrices() {
+    let a = Matrix::new([
+        [Integer::new(2), Integer::new(1)],
+        [Integer::new(1), Integer::new(1)],
+    ]);
+    let inv = a.inverse().unwrap();
+    assert_eq!(a * inv, Matrix::one());
+    let b = Matrix::new([
+        [Integer::new(2), Integer::new(0)],
+        [Integer::new(0), Integer::new(1)],
+    ]);
+    assert_eq!(b.inverse(), None);
+
+    // No entry of the first column is a unit, but the determinant is
+    let c = Matrix::new([
+        [Integer::new(2), Integer::new(3)],
+        [Integer::new(3), Integer::new(5)],
+    ]);
+    assert!(c.is_unit());
+    assert_eq!(
+        c.inverse(),
+        Some(Matrix::new([
+            [Integer::new(5), Integer::new(-3)],
+            [Integer::new(-3), Integer::new(2)],
+        ]))
+    );
+}
+
+#[test]
+fn adjugate() {
+    let a = m(1, 2, 3, 4);
+    assert_eq!(a.adjugate(), m(4, -2, -3, 1));
+
+    let c: Matrix<Rational, 3> = Matrix::from_fn(|i, j| r(((i * 7 + j * 3) % 5) as i128 - 2));
+    let det = Matrix::scalar(c.determinant());
+    assert_eq!(&c * &c.adjugate(), det);
+    assert_eq!(&c.adjugate() * &c, det);
+    assert_eq!(
+        Matrix::<Rational, 1>::new([[r(5)]]).adjugate(),
+        Matrix::one()
+    );
+
+    // Over ℤ/12ℤ the determinant 2·2 - 3·3 = 7 is a unit, although no entry of the first column is
+    let z = |n| ZMod::<12>::new(n);
+    let d = Matrix::new([[z(2), z(3)], [z(3), z(2)]]);
+    let inv = d.inverse().unwrap();
+    assert_eq!(&d * &inv, Matrix::one());
+    assert_eq!(&inv * &d, Matrix::one());
+    assert_eq!(Matrix::new([[z(2), z(3)], [z(3), z(3)]]).inverse(), None);
+}
@@ -0,0 +1,152 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+type F = ZMod<998244353>;
+
+fn random_coefs(len: usize, seed: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 16
+        })
+        .collect()
+}
+
+fn naive<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut out = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] += x.clone() * y.clone();
+        }
+    }
+    out
+}
+
+#[test]
+fn ntt_modular() {
+    let a: Vec<F> = random_coefs(300, 1).into_iter().map(F::new).collect();
+    let b: Vec<F> = random_coefs(177, 2).into_iter().map(F::new).collect();
+    assert_eq!(ntt_convolve(&a, &b), Some(naive(&a, &b)));
+    assert_eq!(ntt_convolve(&a, &a), Some(naive(&a, &a)));
+    assert_eq!(ntt_convolve(&a[..1], &b[..1]), Some(vec![a[0] * b[0]]));
+
+    // 10⁹ + 7 - 1 is only divisible by 2, so only products of length 2 can be transformed
+    type G = ZMod<1000000007>;
+    let c: Vec<G> = vec![G::new(3), G::new(5)];
+    assert_eq!(ntt_convolve(&c[..1], &c[..1]), Some(vec![G::new(9)]));
+    assert_eq!(ntt_convolve(&c, &c), None);
+    // Composite moduli are rejected
+    assert_eq!(ntt_convolve(&[ZMod::<9>::new(1)], &[ZMod::new(1)]), None);
+}
+
+#[test]
+fn ntt_integers() {
+    let small = |len, seed| {
+        random_coefs(len, seed)
+            .into_iter()
+            .map(|c| Integer::from((c % 2001) as i64 - 1000))
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (small(200, 3), small(150, 4));
+    assert_eq!(ntt_convolve_integers(&a, &b), Some(naive(&a, &b)));
+
+    // Coefficients of about 2⁴⁸ need all four primes
+    let large = |len, seed| {
+        random_coefs(len, seed)
+            .into_iter()
+            .map(|c| Integer::new(c as i128 - (1 << 47)))
+            .collect::<Vec<_>>()
+    };
+    let (a, b) = (large(130, 5), large(140, 6));
+    assert_eq!(ntt_convolve_integers(&a, &b), Some(naive(&a, &b)));
+
+    let huge = vec![Integer::new(1 << 60); 200];
+    assert_eq!(ntt_convolve_integers(&huge, &huge), None);
+}
+
+#[test]
+fn fft_floats() {
+    let a: Vec<Float> = (0..300)
+        .map(|i| Float::new((i % 17) as f64 - 8.5))
+        .collect();
+    let b: Vec<Float> = (0..280)
+        .map(|i| Float::new(((i * 7) % 13) as f64 * 0.25))
+        .collect();
+    let expected = naive(&a, &b);
+    let product = fft_convolve(&a, &b);
+    assert_eq!(product.len(), expected.len());
+    for (x, y) in product.iter().zip(&expected) {
+        assert!((*x - *y).abs().value() < 1e-8, "{x} != {y}");
+    }
+
+    let mut values: Vec<Complex<Float>> = (0..8)
+        .map(|i| Complex::from(Float::new(i as f64)))
+        .collect();
+    let original = values.clone();
+    fft(&mut values, false);
+    assert!((values[0].re().value() - 28.0).abs() < 1e-12);
+    fft(&mut values, true);
+    for (x, y) in values.iter().zip(&original) {
+        assert!((x.re().value() / 8.0 - y.re().value()).abs() < 1e-12);
+        assert!((x.im().value() / 8.0).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn automatic_selection() {
+    // ZMod with an NTT prime
+    let a: Polynomial<F> =
+        Polynomial::from_vec(random_coefs(400, 7).into_iter().map(F::new).collect());
+    let b: Polynomial<F> =
+        Polynomial::from_vec(random_coefs(300, 8).into_iter().map(F::new).collect());
+    assert_eq!((&a * &b).coefs(), &naive(a.coefs(), b.coefs()));
+    assert_eq!(a.square().coefs(), &naive(a.coefs(), a.coefs()));
+
+    // Integers via multiple primes
+    let ints: Polynomial<Integer> = Polynomial::from_vec(
+        random_coefs(300, 9)
+            .into_iter()
+            .map(|c| Integer::from((c % 1000) as i64 - 500))
+            .collect(),
+    );
+    assert_eq!((&ints * &ints).coefs(), &naive(ints.coefs(), ints.coefs()));
+
+    // Floats via FFT
+    let floats: Polynomial<Float> =
+        Polynomial::from_vec((0..300).map(|i| Float::new((i % 5) as f64 + 1.0)).collect());
+    let product = &floats * &floats;
+    for (x, y) in product
+        .coefs()
+        .iter()
+        .zip(naive(floats.coefs(), floats.coefs()))
+    {
+        assert!((*x - y).abs().value() < 1e-8);
+    }
+
+    // Complex floats via three real FFT convolutions
+    let complex_floats: Vec<Complex<Float>> = (0..300)
+        .map(|i| Complex::new(Float::new((i % 7) as f64), Float::new((i % 3) as f64 - 1.0)))
+        .collect();
+    let p: Polynomial<Complex<Float>> = Polynomial::from_vec(complex_floats.clone());
+    let product = &p * &p;
+    for (x, y) in product
+        .coefs()
+        .iter()
+        .zip(naive(&complex_floats, &complex_floats))
+    {
+        assert!((*x.re() - *y.re()).abs().value() < 1e-6);
+        assert!((*x.im() - *y.im()).abs().value() < 1e-6);
+    }
+
+    // Complex numbers via three real convolutions
+    let complex: Vec<Complex<F>> = random_coefs(600, 10)
+        .chunks(2)
+        .map(|c| Complex::new(F::new(c[0]), F::new(c[1])))
+        .collect();
+    let p: Polynomial<Complex<F>> = Polynomial::from_vec(complex.clone());
+    assert_eq!(p.square().coefs(), &naive(&complex, &complex));
+}
@@ -0,0 +1,464 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+type P = Polynomial<Rational>;
+
+fn r(n: i128) -> Rational {
+    Rational::from(Integer::new(n))
+}
+
+fn p(coefs: &[i128]) -> P {
+    Polynomial::from_vec(coefs.iter().map(|c| r(*c)).collect())
+}
+
+#[test]
+fn new_poly() {
+    assert_eq!(P::new().coefs(), &Vec::<Rational>::new());
+    assert_eq!(P::with_capacity(10), P::new());
+    assert_eq!(p(&[1, 2, 3]).coefs(), &vec![r(1), r(2), r(3)]);
+    assert_eq!(p(&[0, 0, 0]), P::zero());
+    assert_eq!(p(&[1, 0, 0]), P::one());
+    assert_eq!(P::monomial(r(3), 2), p(&[0, 0, 3]));
+    assert_eq!(P::x(), p(&[0, 1]));
+    assert_eq!(P::from(r(5)), p(&[5]));
+}
+
+#[test]
+fn display() {
+    assert_eq!(P::new().to_string(), "0");
+    assert_eq!(p(&[1, 2, 3]).to_string(), "3x^2 + 2x + 1");
+    assert_eq!(p(&[3, 5, 0, 7]).to_string(), "7x^3 + 5x + 3");
+    assert_eq!(p(&[0, 0, 0, 0, 0, 2, -37]).to_string(), "-37x^6 + 2x^5");
+    assert_eq!(p(&[-1, 1]).to_string(), "x + -1");
+
+    let q: Polynomial<P> = Polynomial::from_vec(vec![p(&[1, 1]), P::zero(), P::one()]);
+    assert_eq!(q.to_string(), "x^2 + (x + 1)");
+}
+
+#[test]
+fn getters() {
+    let q = p(&[3, 5, 0, 7]);
+    assert_eq!(q.degree(), Some(3));
+    assert_eq!(P::zero().degree(), None);
+    assert_eq!(q.len(), 4);
+    assert_eq!(q.get(1), Some(&r(5)));
+    assert_eq!(q.get(4), None);
+    assert_eq!(q.coef(10), r(0));
+    assert_eq!(q.leading_coef(), Some(&r(7)));
+    assert!(!q.is_monic());
+    assert_eq!(
+        q.terms().map(|(i, c)| (i, *c)).collect::<Vec<_>>(),
+        vec![(0, r(3)), (1, r(5)), (3, r(7))]
+    );
+
+    let mut q = q;
+    q.set_coef(3, r(0));
+    assert_eq!(q, p(&[3, 5]));
+    q.set_coef(5, r(1));
+    assert_eq!(q, p(&[3, 5, 0, 0, 0, 1]));
+    assert!(q.is_monic());
+}
+
+#[test]
+fn shift() {
+    let mut q = p(&[1, 2, 3]);
+    q.shl(2);
+    assert_eq!(q, p(&[0, 0, 1, 2, 3]));
+    q.shr(3);
+    assert_eq!(q, p(&[2, 3]));
+    q.shr(5);
+    assert_eq!(q, P::zero());
+    assert_eq!(p(&[1, 2]).simple_mul(&r(3), 1), p(&[0, 3, 6]));
+}
+
+#[test]
+fn ops() {
+    let p1 = p(&[0, 0, 3, 5, 0, 7]);
+    let p2 = p(&[0, 0, 15, 0, 0, 0]);
+    let p3 = p(&[-1, 0, 5, 2]);
+    assert_eq!(p1.clone() + p2.clone(), p(&[0, 0, 18, 5, 0, 7]));
+    assert_eq!(p1.clone() - p1.clone(), P::zero());
+    assert_eq!(-p3.clone(), p(&[1, 0, -5, -2]));
+    assert_eq!(p3.clone() - p2.clone(), p(&[-1, 0, -10, 2]));
+    assert_eq!(
+        (p1.clone() * p2.clone()).to_string(),
+        "105x^7 + 75x^5 + 45x^4"
+    );
+    assert_eq!(p(&[1, 1]) * p(&[-1, 1]), p(&[-1, 0, 1]));
+
+    let mut q = p3.clone();
+    q *= P::zero();
+    assert!(q.is_zero());
+
+    assert_eq!(p3.eval(&r(2)), r(35));
+    assert_eq!(p3.derivative(), p(&[0, 10, 6]));
+}
+
+#[test]
+fn zero_divisors() {
+    // (2x + 1) * (2x + 1) = 1 over Z/4Z, so the product loses its leading terms
+    let q: Polynomial<_> = Polynomial::from_vec(vec![ZMod::<4>::new(1), ZMod::new(2)]);
+    assert_eq!(q.clone() * q.clone(), Polynomial::one());
+    assert_eq!(q.inverse(), None);
+    assert_eq!(
+        Polynomial::<_>::from(ZMod::<4>::new(3)).inverse(),
+        Some(Polynomial::from(ZMod::new(3)))
+    );
+}
+
+#[test]
+fn div() {
+    let (q, rem) = p(&[15, 8, 1]).div_rem(&p(&[5, 1]));
+    assert_eq!((q, rem), (p(&[3, 1]), P::zero()));
+
+    let (q, rem) = p(&[6, -3, 1]).div_rem(&p(&[2, 1]));
+    assert_eq!((q, rem), (p(&[-5, 1]), p(&[16])));
+
+    let (q, rem) = p(&[1, 0, 0, 1]).div_rem(&p(&[0, 2]));
+    assert_eq!(q.to_string(), "1/2x^2");
+    assert_eq!(rem, p(&[1]));
+
+    let a = p(&[-4, 0, -2, 1]);
+    let b = p(&[-3, 1]);
+    assert_eq!(a.clone() / b.clone(), p(&[3, 1, 1]));
+    assert_eq!(a.clone() % b.clone(), p(&[5]));
+    assert_eq!(p(&[1, 2]) / p(&[1, 2, 3]), P::zero());
+}
+
+#[test]
+fn non_field_division() {
+    type I = Polynomial<Integer>;
+    let int = |coefs: &[i128]| I::from_vec(coefs.iter().map(|&c| Integer::from(c)).collect());
+    let zmod = |coefs: &[u64]| {
+        Polynomial::<ZMod<4>>::from_vec(coefs.iter().map(|&c| ZMod::new(c)).collect())
+    };
+
+    // 4 * (3x² + x + 1) = (6x - 1) * (2x + 1) + 5
+    let (a, b) = (int(&[1, 1, 3]), int(&[1, 2]));
+    let (q, r) = a.pseudo_div_rem(&b).unwrap();
+    assert_eq!((q.clone(), r.clone()), (int(&[-1, 6]), int(&[5])));
+    assert_eq!(a.simple_mul(&Integer::from(4i128), 0), q * b.clone() + r);
+    assert_eq!(int(&[1]).pseudo_div_rem(&b), Ok((I::zero(), int(&[1]))));
+    assert_eq!(
+        a.pseudo_div_rem(&I::zero()),
+        Err(PolynomialDivErr::DivideByZero)
+    );
+
+    assert_eq!(int(&[-2, 0, 2]).exact_div(&int(&[2, 2])), Ok(int(&[-1, 1])));
+    assert_eq!(a.exact_div(&b), Err(PolynomialDivErr::NotExact));
+    assert_eq!(
+        int(&[1, 0, 1]).exact_div(&int(&[1, 1])),
+        Err(PolynomialDivErr::NotExact)
+    );
+    assert_eq!(a.exact_div(&I::zero()), Err(PolynomialDivErr::DivideByZero));
+    assert_eq!(
+        PolynomialDivErr::NotExact.to_string(),
+        "the divisor doesn't divide the dividend"
+    );
+
+    // Monic divisors and divisors with a unit leading coefficient work over every ring
+    assert_eq!(
+        a.try_div_rem(&int(&[1, -1])),
+        Ok((int(&[-4, -3]), int(&[5])))
+    );
+    assert_eq!(
+        a.try_div_rem(&b),
+        Err(PolynomialDivErr::NonUnitLeadingCoefficient)
+    );
+    let (q, r) = zmod(&[1, 2, 3]).try_div_rem(&zmod(&[1, 3])).unwrap();
+    assert_eq!(q * zmod(&[1, 3]) + r, zmod(&[1, 2, 3]));
+    assert_eq!(
+        zmod(&[1, 2, 3]).try_div_rem(&zmod(&[1, 2])),
+        Err(PolynomialDivErr::NonUnitLeadingCoefficient)
+    );
+}
+
+#[test]
+#[should_panic]
+fn div_by_zero() {
+    let _ = p(&[1, 2]).div_rem(&P::zero());
+}
+
+#[test]
+fn euclidean_domain() {
+    let a = p(&[-1, 0, 1]);
+    let b = p(&[1, 2, 1]);
+    assert_eq!(gcd(a.clone(), b.clone()), p(&[1, 1]));
+
+    let ExtendedGcd { gcd: g, x, y } = extended_gcd(a.clone(), b.clone());
+    assert_eq!(x * a + y * b, g);
+    assert_eq!(p(&[2, 4]).unit_normal().to_string(), "x + 1/2");
+    assert_eq!(p(&[2, 4]).euclidean_value(), 2);
+    assert_eq!(P::zero().euclidean_value(), 0);
+
+    // x ≡ 1 (mod x - 1) and x ≡ 2 (mod x + 1)
+    let (x, m) = crt(&[(p(&[1]), p(&[-1, 1])), (p(&[2]), p(&[1, 1]))]).unwrap();
+    assert_eq!(m, p(&[-1, 0, 1]));
+    assert_eq!(x.eval(&r(1)), r(1));
+    assert_eq!(x.eval(&r(-1)), r(2));
+}
+
+#[test]
+fn polynomial_gcd() {
+    // (x - 1)(x + 2)², (x - 1)(x + 2)(x + 3) and 2(x + 2)
+    let a = p(&[-4, 0, 3, 1]);
+    let b = p(&[-6, 1, 4, 1]);
+    let c = p(&[4, 2]);
+    assert_eq!(gcd(a.simple_mul(&r(3), 0), b.clone()), p(&[-2, 1, 1]));
+    assert!(gcd(a.clone(), b.clone()).is_monic());
+    assert_eq!(gcd(P::zero(), c.clone()), p(&[2, 1]));
+    assert_eq!(gcd(p(&[1, 1]), p(&[-1, 1])), P::one());
+
+    let ExtendedGcd { gcd: g, x, y } = extended_gcd(a.clone(), b.clone());
+    assert_eq!(g, p(&[-2, 1, 1]));
+    assert_eq!(x * a.clone() + y * b.clone(), g);
+
+    assert_eq!(lcm(a.clone(), b.clone()), p(&[-12, -4, 9, 6, 1]));
+    assert_eq!(lcm(c.clone(), P::zero()), P::zero());
+    assert_eq!(gcd_all([a.clone(), b.clone(), c.clone()]), p(&[2, 1]));
+    assert_eq!(gcd_all([a.clone(), b.clone(), p(&[3, 1])]), P::one());
+    assert_eq!(lcm_all([a.clone(), b.clone(), c]), lcm(a, b));
+}
+
+#[test]
+fn inverse_mod_polynomial() {
+    // x² + 1 is irreducible over the Rationals, so every non-zero residue is invertible
+    let f = p(&[1, 0, 1]);
+    let a = p(&[2, 3]);
+    let inv = a.inverse_mod(&f).unwrap();
+    assert!(inv.degree() < f.degree());
+    assert_eq!((inv * a) % f.clone(), P::one());
+    assert_eq!(p(&[0, 0, 0, 1]).inverse_mod(&f), Some(p(&[0, 1])));
+    assert_eq!(p(&[1, 0, 1]).inverse_mod(&f), None);
+    assert_eq!(p(&[-1, 1]).inverse_mod(&p(&[-1, 0, 1])), None);
+    assert_eq!(p(&[2]).inverse_mod(&P::zero()), Some(P::from(r(1) / r(2))));
+}
+
+#[test]
+fn nested() {
+    // Bivariate polynomials in x over Q[y]
+    type Q = Polynomial<P>;
+    let y = Q::from(P::x());
+    let x = Q::x();
+    let sum = x.clone() + y.clone();
+    let diff = x.clone() - y.clone();
+    assert_eq!(
+        sum.clone() * diff,
+        x.clone() * x.clone() - y.clone() * y.clone()
+    );
+    assert_eq!(
+        (sum.clone() * sum.clone()).to_string(),
+        "x^2 + (2x)x + (x^2)"
+    );
+    assert_eq!(sum.eval(&P::one()), p(&[1, 1]));
+    assert_eq!(Q::one().inverse(), Some(Q::one()));
+    assert_eq!(y.inverse(), None);
+}
+
+#[test]
+fn quaternion_coefficients() {
+    type H = Quaternion<Rational>;
+    let h = |w, x, y, z| H::new(r(w), r(x), r(y), r(z));
+    let a: Polynomial<H> = Polynomial::from_vec(vec![h(1, 2, 0, -1), h(0, 3, 1, 0), h(2, 0, 0, 1)]);
+    let b = Polynomial::from_vec(vec![h(0, 1, 1, 0), h(1, 0, 2, 0)]);
+
+    let (q, rem) = a.right_div_rem(&b);
+    assert_eq!(q.clone() * b.clone() + rem.clone(), a);
+    assert!(rem.degree().unwrap_or(0) < 1);
+
+    let (q2, rem2) = a.left_div_rem(&b);
+    assert_eq!(b.clone() * q2.clone() + rem2, a);
+    assert_ne!(q, q2);
+}
+
+#[test]
+fn reference_ops() {
+    let a = p(&[1, 2, 3]);
+    let b = p(&[-1, 1]);
+    assert_eq!(&a + &b, a.clone() + b.clone());
+    assert_eq!(&a - &b, a.clone() - b.clone());
+    assert_eq!(&a * &b, a.clone() * b.clone());
+    assert_eq!(-&a, -a.clone());
+    assert_eq!(&a / &b, a.clone() / b.clone());
+    assert_eq!(&a % &b, a.clone() % b.clone());
+
+    let mut c = a.clone();
+    c += &b;
+    c -= &b;
+    c *= &b;
+    c /= &b;
+    assert_eq!(c, a);
+}
+
+#[test]
+fn nested_by_reference() {
+    type Q = Polynomial<P>;
+    let x = Q::x();
+    let y = Q::from(P::x());
+    let sum = &x + &y;
+    let cube = &(&sum * &sum) * &sum;
+    assert_eq!(cube.coef(0), p(&[0, 0, 0, 1]));
+    assert_eq!(cube.coef(1), p(&[0, 0, 3]));
+    assert_eq!(cube.coef(2), p(&[0, 3]));
+    assert_eq!(cube.coef(3), P::one());
+    assert_eq!(cube.eval(&P::x()), p(&[0, 0, 0, 8]));
+}
+
+#[test]
+fn matrix_coefficients() {
+    type M = Matrix<Rational, 2>;
+    let a: M = Matrix::new([[r(1), r(2)], [r(3), r(4)]]);
+
+    // Cayley-Hamilton: the characteristic polynomial x² - 5x - 2 vanishes at a
+    let char_poly: Polynomial<M> =
+        Polynomial::from_vec(vec![M::scalar(r(-2)), M::scalar(r(-5)), M::one()]);
+    assert_eq!(char_poly.eval(&a), M::zero());
+
+    // The coefficients don't commute, so neither do the polynomials
+    let b: M = Matrix::new([[r(0), r(1)], [r(1), r(0)]]);
+    let p1: Polynomial<M> = Polynomial::from_vec(vec![M::one(), a.clone()]);
+    let p2: Polynomial<M> = Polynomial::from_vec(vec![M::one(), b.clone()]);
+    assert_eq!((&p1 * &p2).coef(2), &a * &b);
+    assert_eq!((&p2 * &p1).coef(2), &b * &a);
+    assert_ne!(&p1 * &p2, &p2 * &p1);
+    assert_eq!(
+        (&p1 * &p2).to_string(),
+        "([[2, 1], [4, 3]])x^2 + ([[1, 3], [4, 4]])x + ([[1, 0], [0, 1]])"
+    );
+}
+
+#[test]
+fn variables() {
+    let y: Polynomial<Rational, Y> = Polynomial::x();
+    assert_eq!((&y * &y + Polynomial::one()).to_string(), "y^2 + 1");
+    assert_eq!(p(&[1, 2]).with_variable::<Z>().to_string(), "2z + 1");
+
+    type Q = Polynomial<Polynomial<Rational, Y>>;
+    let sum = Q::x() + Q::from(y);
+    assert_eq!((&sum * &sum).to_string(), "x^2 + (2y)x + (y^2)");
+}
+
+/// Pseudo-random coefficients from a linear congruential generator.
+fn random_coefs(len: usize, seed: u64) -> Vec<u64> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 40
+        })
+        .collect()
+}
+
+fn naive_mul<R: Ring>(a: &[R], b: &[R]) -> Vec<R> {
+    let mut out = vec![R::zero(); a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            out[i + j] += x.clone() * y.clone();
+        }
+    }
+    out
+}
+
+fn check_mul<R: Ring + std::fmt::Debug>(a: Vec<R>, b: Vec<R>) {
+    let expected = Polynomial::<R>::from_vec(naive_mul(&a, &b));
+    let (pa, pb) = (
+        Polynomial::<R>::from_vec(a.clone()),
+        Polynomial::from_vec(b),
+    );
+    assert_eq!(&pa * &pb, expected);
+    assert_eq!(&pa * &pa, Polynomial::from_vec(naive_mul(&a, &a)));
+    assert_eq!(pa.square(), &pa * &pa.clone());
+}
+
+#[test]
+fn fast_multiplication() {
+    type F = ZMod<998244353>;
+    let zmod = |len, seed| {
+        random_coefs(len, seed)
+            .into_iter()
+            .map(F::new)
+            .collect::<Vec<_>>()
+    };
+    let int = |len, seed| {
+        random_coefs(len, seed)
+            .into_iter()
+            .map(|c| Integer::from(c as i64 - (1 << 23)))
+            .collect::<Vec<_>>()
+    };
+    let bits = |len, seed| {
+        random_coefs(len, seed)
+            .into_iter()
+            .map(ZMod::<2>::new)
+            .collect::<Vec<_>>()
+    };
+
+    // Toom-3 and Karatsuba over a field, including unbalanced operands
+    for (n, m) in [(300, 300), (301, 250), (40, 500), (97, 96), (1000, 37)] {
+        check_mul(zmod(n, 1), zmod(m, 2));
+    }
+    // 10⁹ + 7 doesn't support the NTT, so the generic algorithms are used for all lengths
+    let other = |len, seed| {
+        random_coefs(len, seed)
+            .into_iter()
+            .map(ZMod::<1000000007>::new)
+            .collect::<Vec<_>>()
+    };
+    check_mul(other(300, 11), other(280, 12));
+    // Karatsuba, as 2 isn't invertible
+    check_mul(int(200, 3), int(150, 4));
+    check_mul(bits(257, 5), bits(300, 6));
+}
+
+#[test]
+fn fast_multiplication_non_commutative() {
+    type M = Matrix<ZMod<7>, 2>;
+    let matrices = |len: usize, seed| {
+        random_coefs(4 * len, seed)
+            .chunks(4)
+            .map(|c| {
+                Matrix::new([
+                    [ZMod::new(c[0]), ZMod::new(c[1])],
+                    [ZMod::new(c[2]), ZMod::new(c[3])],
+                ])
+            })
+            .collect::<Vec<M>>()
+    };
+    check_mul(matrices(150, 7), matrices(130, 8));
+}
+
+#[test]
+fn powers() {
+    let a = p(&[1, 1]);
+    assert_eq!(a.pow(0), P::one());
+    assert_eq!(a.pow(1), a);
+    assert_eq!(a.pow(5), p(&[1, 5, 10, 10, 5, 1]));
+    assert_eq!(P::zero().pow(3), P::zero());
+}
+
+#[test]
+fn fast_division() {
+    type F = ZMod<998244353>;
+    let poly = |len, seed| {
+        Polynomial::<F>::from_vec(random_coefs(len, seed).into_iter().map(F::new).collect())
+    };
+
+    // Newton iteration is used if both the quotient and the divisor are long enough
+    for (n, m) in [(2000, 1000), (1500, 200), (700, 650), (300, 20), (100, 100)] {
+        let (a, b) = (poly(n, 1), poly(m, 2));
+        let (q, r) = a.div_rem(&b);
+        assert_eq!((q.clone(), r.clone()), a.right_div_rem(&b));
+        assert_eq!(&q * &b + r, a);
+    }
+
+    let f = poly(500, 3);
+    let inv = f.inverse_series(300);
+    assert_eq!(inv.len(), 300);
+    let mut product = (&f * &inv).into_coefs();
+    product.truncate(300);
+    assert_eq!(Polynomial::<F>::from_vec(product), Polynomial::one());
+    assert_eq!(p(&[1, -1]).inverse_series(4), p(&[1, 1, 1, 1]));
+    assert_eq!(p(&[1, 1]).inverse_series(0), P::zero());
+}
@@ -0,0 +1,131 @@
+use polydiv::number_theory::*;
+use polydiv::types::*;
+
+fn nat(n: u64) -> Natural {
+    Natural::new(n)
+}
+
+fn naive_is_prime(n: u64) -> bool {
+    n >= 2
+        && (2..)
+            .take_while(|d| d * d <= n)
+            .all(|d| !n.is_multiple_of(d))
+}
+
+const PRIMES: [u64; 6] = [
+    2,
+    998244353,
+    1_000_000_007,
+    4_294_967_311,
+    2_305_843_009_213_693_951,
+    18_446_744_073_709_551_557,
+];
+
+const COMPOSITES: [u64; 8] = [
+    1,
+    561,
+    2047,
+    3_215_031_751,
+    3_825_123_056_546_413_051,
+    4_294_967_297,
+    1_000_000_007 * 998244353,
+    u64::MAX,
+];
+
+#[test]
+fn small_numbers() {
+    for n in 0..2000 {
+        assert_eq!(nat(n).is_prime(), naive_is_prime(n), "{n}");
+        assert_eq!(is_prime_bpsw(nat(n)), naive_is_prime(n), "{n}");
+    }
+}
+
+#[test]
+fn large_numbers() {
+    for p in PRIMES {
+        assert!(nat(p).is_prime(), "{p}");
+        assert!(is_prime_bpsw(nat(p)), "{p}");
+    }
+    for n in COMPOSITES {
+        assert!(!nat(n).is_prime(), "{n}");
+        assert!(!is_prime_bpsw(nat(n)), "{n}");
+    }
+}
+
+#[test]
+fn pseudoprimes() {
+    // Strong pseudoprimes to base 2
+    for n in [2047, 3277, 4033, 4681, 8321, 3_215_031_751] {
+        assert!(is_strong_probable_prime(nat(n), nat(2)), "{n}");
+        assert!(!nat(n).is_prime(), "{n}");
+        assert!(!is_prime_bpsw(nat(n)), "{n}");
+    }
+    // Strong Lucas pseudoprimes
+    for n in [5459, 5777, 10877, 16109, 18971] {
+        assert!(is_strong_lucas_probable_prime(nat(n)), "{n}");
+        assert!(!nat(n).is_prime(), "{n}");
+        assert!(!is_prime_bpsw(nat(n)), "{n}");
+    }
+}
+
+#[test]
+fn bases_divisible_by_n() {
+    assert!(!is_strong_probable_prime(nat(9), nat(9)));
+    assert!(!is_strong_probable_prime(nat(9), nat(18)));
+    assert!(!is_strong_probable_prime(nat(7), nat(0)));
+    assert!(is_strong_probable_prime(nat(7), nat(8)));
+}
+
+#[test]
+fn factorization() {
+    for n in 1..3000 {
+        let f = factor(nat(n));
+        assert_eq!(f.product(), nat(n));
+        assert!(f.primes().all(|p| p.is_prime()));
+    }
+
+    let f = nat(360).factor();
+    assert_eq!(f.factors(), &vec![(nat(2), 3), (nat(3), 2), (nat(5), 1)]);
+    assert_eq!(format!("{f}"), "2^3 * 3^2 * 5");
+    assert_eq!(format!("{}", nat(1).factor()), "1");
+    assert_eq!(f.count(), 6);
+}
+
+#[test]
+fn large_factorizations() {
+    let f = factor(nat(1_000_000_007 * 998244353));
+    assert_eq!(
+        f.factors(),
+        &vec![(nat(998244353), 1), (nat(1_000_000_007), 1)]
+    );
+    for n in [
+        u64::MAX,
+        u64::MAX - 1,
+        4_294_967_297,
+        3_825_123_056_546_413_051,
+        600_851_475_143,
+        4_294_967_291 * 4_294_967_279,
+        (1 << 61) - 1,
+    ] {
+        let f = factor(nat(n));
+        assert_eq!(f.product(), nat(n), "{n}");
+        assert!(f.primes().all(|p| p.is_prime()), "{n}");
+    }
+    assert_eq!(
+        format!("{}", factor(nat(u64::MAX))),
+        "3 * 5 * 17 * 257 * 641 * 65537 * 6700417"
+    );
+}
+
+#[test]
+fn pollard_rho() {
+    assert_eq!(pollard_rho_brent(nat(13)), None);
+    let d = pollard_rho_brent(nat(8051)).unwrap().value();
+    assert!(d == 83 || d == 97);
+}
+
+#[test]
+#[should_panic(expected = "Cannot factor zero")]
+fn factor_zero() {
+    let _ = factor(nat(0));
+}
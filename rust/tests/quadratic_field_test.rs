@@ -0,0 +1,140 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+fn rat(n: i128, d: i128) -> Rational {
+    Rational::new(Integer::new(n), Integer::new(d))
+}
+
+fn q<const D: i64>(a: Rational, b: Rational) -> QuadraticField<D> {
+    QuadraticField::new(a, b)
+}
+
+fn o<const D: i64>(a: i128, b: i128) -> QuadraticInteger<D> {
+    QuadraticInteger::new(Integer::new(a), Integer::new(b))
+}
+
+#[test]
+fn field_arithmetic() {
+    type K = QuadraticField<2>;
+    let x: K = q(rat(1, 1), rat(1, 1));
+    assert_eq!(x * x.conjugate(), K::from(-1));
+    assert_eq!(x.norm(), rat(-1, 1));
+    assert_eq!(x.trace(), rat(2, 1));
+    assert_eq!(K::sqrt_d() * K::sqrt_d(), K::from(2));
+    assert_eq!(x.multiplicative_inverse(), q(rat(-1, 1), rat(1, 1)));
+
+    let y: K = q(rat(1, 3), rat(-5, 2));
+    assert_eq!(y / y, K::one());
+    assert_eq!((x / y) * y, x);
+    assert_eq!(y.norm(), y.conjugate().norm());
+    assert_eq!((x * y).norm(), x.norm() * y.norm());
+    assert_eq!(
+        y.inverse().unwrap().norm(),
+        y.norm().multiplicative_inverse()
+    );
+    assert_eq!(K::zero().inverse(), None);
+
+    assert_eq!(y.to_string(), "1/3 - 5/2√2");
+    assert_eq!(K::sqrt_d().to_string(), "√2");
+    assert_eq!((-K::sqrt_d()).to_string(), "-√2");
+    assert_eq!(x.to_string(), "1 + √2");
+
+    type L = QuadraticField<-3>;
+    let z: L = q(rat(1, 2), rat(1, 2));
+    assert_eq!(z.norm(), rat(1, 1));
+    assert!(z.is_integral());
+    assert!(!L::from(rat(1, 2)).is_integral());
+    // (1 + √-3) / 2 is a primitive sixth root of unity
+    assert_eq!((z * z * z).a(), rat(-1, 1));
+}
+
+#[test]
+fn ring_of_integers() {
+    type O = QuadraticInteger<5>;
+    // ω = (1 + √5) / 2 is the golden ratio with ω² = ω + 1
+    let omega = O::omega();
+    assert_eq!(omega * omega, omega + O::one());
+    assert_eq!(omega.norm(), Integer::new(-1));
+    assert_eq!(omega.trace(), Integer::new(1));
+    assert_eq!(omega.inverse(), Some(o(-1, 1)));
+    assert_eq!(QuadraticField::from(omega), q(rat(1, 2), rat(1, 2)));
+    assert_eq!(O::try_from(q::<5>(rat(3, 2), rat(1, 2))), Ok(o(1, 1)));
+    assert!(O::try_from(q::<5>(rat(1, 2), rat(0, 1))).is_err());
+    assert_eq!(omega.conjugate(), o(1, -1));
+    assert_eq!(omega.to_string(), "1/2 + 1/2√5");
+
+    type P = QuadraticInteger<-5>;
+    assert_eq!(P::omega() * P::omega(), P::from(-5i64));
+    assert_eq!(o::<-5>(1, 1).norm(), Integer::new(6));
+    assert_eq!(o::<-5>(2, 0).inverse(), None);
+    // Z[√-5] is not a UFD: 6 = 2 * 3 = (1 + √-5)(1 - √-5)
+    assert_eq!(o::<-5>(1, 1) * o(1, -1), P::from(6i64));
+}
+
+fn check_division<const D: i64>()
+where
+    QuadraticInteger<D>: NormEuclidean,
+{
+    let values = [
+        o::<D>(17, 5),
+        o(-23, 11),
+        o(100, -7),
+        o(3, 0),
+        o(0, 1),
+        o(1, 1),
+        o(-40, -40),
+        o(12345, 678),
+    ];
+    for a in values {
+        for b in values {
+            let (quot, rem) = a.div_rem(&b);
+            assert_eq!(quot * b + rem, a, "D = {D}");
+            assert!(
+                rem.euclidean_value() < b.euclidean_value(),
+                "D = {D}: {a} / {b}"
+            );
+        }
+    }
+}
+
+#[test]
+fn euclidean_division() {
+    check_division::<-11>();
+    check_division::<-7>();
+    check_division::<-3>();
+    check_division::<-2>();
+    check_division::<-1>();
+    check_division::<2>();
+    check_division::<3>();
+    check_division::<5>();
+    check_division::<6>();
+    check_division::<7>();
+    check_division::<11>();
+    check_division::<13>();
+    check_division::<17>();
+    check_division::<19>();
+    check_division::<21>();
+    check_division::<29>();
+    check_division::<33>();
+    check_division::<37>();
+    check_division::<41>();
+    check_division::<57>();
+    check_division::<73>();
+}
+
+#[test]
+fn gcds() {
+    // 3 = (1 + √-2)(1 - √-2) and 11 = (3 + √-2)(3 - √-2)
+    let a = o::<-2>(1, 1) * o(3, 1);
+    let b = o::<-2>(1, 1) * o(3, -1);
+    let ExtendedGcd { gcd, x, y } = extended_gcd(a, b);
+    assert_eq!(x * a + y * b, gcd);
+    assert_eq!(gcd.euclidean_value(), 3);
+
+    // In Z[√2] the gcd is only determined up to the infinitely many units
+    let a = o::<2>(7, 0);
+    let b = o::<2>(3, 1) * o(5, 2);
+    let g = polydiv::util::gcd(a, b);
+    assert_eq!(g.norm().abs(), Integer::new(7));
+}
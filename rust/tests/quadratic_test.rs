@@ -0,0 +1,145 @@
+use polydiv::number_theory::*;
+use polydiv::types::*;
+
+fn nat(n: u64) -> Natural {
+    Natural::new(n)
+}
+
+fn int(n: i128) -> Integer {
+    Integer::new(n)
+}
+
+#[test]
+fn jacobi_symbols() {
+    // Values from https://en.wikipedia.org/wiki/Jacobi_symbol#Table_of_values
+    assert_eq!(jacobi_symbol(int(1), nat(1)), 1);
+    assert_eq!(jacobi_symbol(int(2), nat(15)), 1);
+    assert_eq!(jacobi_symbol(int(7), nat(15)), -1);
+    assert_eq!(jacobi_symbol(int(5), nat(15)), 0);
+    assert_eq!(jacobi_symbol(int(-1), nat(7)), -1);
+    assert_eq!(jacobi_symbol(int(-1), nat(13)), 1);
+    assert_eq!(jacobi_symbol(int(1001), nat(9907)), -1);
+
+    for p in [3, 5, 7, 11, 13, 101] {
+        for a in 0..p {
+            let is_square = (1..p).any(|x| x * x % p == a);
+            let expected = if a == 0 {
+                0
+            } else if is_square {
+                1
+            } else {
+                -1
+            };
+            assert_eq!(legendre_symbol(int(a as i128), nat(p)), expected);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn jacobi_even() {
+    jacobi_symbol(int(3), nat(8));
+}
+
+#[test]
+fn square_roots_mod_prime() {
+    // 1_000_000_007 = 3 mod 4, 7681 = 15 * 2^9 + 1 uses Tonelli-Shanks, 998244353 = 119 * 2^23 + 1 uses Cipolla
+    let primes = [
+        2,
+        3,
+        17,
+        97,
+        7681,
+        998244353,
+        1_000_000_007,
+        18446744073709551557,
+    ];
+    for p in primes {
+        let ring = Zn::new(p).unwrap();
+        for x in [0, 1, 2, 3, 12345, 987654321, p - 1, p / 2] {
+            let square = ring.element(x) * ring.element(x);
+            let root = sqrt_mod_prime(nat(square.value()), nat(p)).unwrap();
+            let root = ring.element(root.value());
+            assert_eq!(root * root, square);
+            assert!(root.value() <= p / 2 || p == 2);
+        }
+    }
+
+    for p in [3, 7, 13, 7681] {
+        for a in 0..p {
+            let exists = (0..p).any(|x| x * x % p == a);
+            assert_eq!(sqrt_mod_prime(nat(a), nat(p)).is_some(), exists);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "The modulus has to be a prime")]
+fn sqrt_mod_odd_composite() {
+    sqrt_mod_prime(nat(4), nat(21));
+}
+
+#[test]
+#[should_panic(expected = "The modulus has to be a prime")]
+fn sqrt_mod_even_composite() {
+    sqrt_mod_prime(nat(3), nat(8));
+}
+
+#[test]
+fn square_roots_mod_prime_powers() {
+    for (p, k) in [
+        (2, 1),
+        (2, 2),
+        (2, 3),
+        (2, 7),
+        (3, 4),
+        (5, 3),
+        (7, 2),
+        (13, 2),
+    ] {
+        let m: u64 = u64::pow(p, k);
+        for a in 0..m {
+            let exists = (0..m).any(|x| x * x % m == a);
+            match sqrt_mod_prime_power(int(a as i128), nat(p), k) {
+                Some(root) => assert_eq!(root.value() * root.value() % m, a),
+                None => assert!(!exists, "{a} has a square root modulo {p}^{k}"),
+            }
+        }
+    }
+
+    let p = 1_000_000_007;
+    let m = p as i128 * p as i128;
+    let root = sqrt_mod_prime_power(int(-4), nat(p), 2);
+    assert!(root.is_none());
+    let square = 123456789123456789 % m * (123456789123456789 % m) % m;
+    let root = sqrt_mod_prime_power(int(square), nat(p), 2)
+        .unwrap()
+        .value() as i128;
+    assert_eq!(root * root % m, square);
+    assert_eq!(sqrt_mod_prime_power(int(-3), nat(2), 10), None);
+    assert_eq!(
+        sqrt_mod_prime_power(int(-7), nat(2), 60).map(|r| {
+            let r = r.value() as u128;
+            (r * r) % (1 << 60)
+        }),
+        Some((1u128 << 60) - 7)
+    );
+}
+
+#[test]
+fn zmod_square_roots() {
+    type F = ZMod<998244353>;
+    assert_eq!(F::new(0).legendre(), 0);
+    assert_eq!(F::new(4).legendre(), 1);
+    // 3 is a primitive root modulo 998244353
+    assert_eq!(F::new(3).legendre(), -1);
+    assert!(!F::new(3).is_square());
+    assert_eq!(F::new(3).sqrt(), None);
+    assert_eq!(F::new(4).sqrt(), Some(F::new(2)));
+    let x = F::new(123456789);
+    let root = (x * x).sqrt().unwrap();
+    assert!(root == x || root == -x);
+
+    assert_eq!(ZMod::<2>::new(1).sqrt(), Some(ZMod::new(1)));
+    assert_eq!(ZMod::<2>::new(1).legendre(), 1);
+}
@@ -0,0 +1,90 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+
+type H = Quaternion<Rational>;
+
+fn r(n: i128) -> Rational {
+    Rational::from(Integer::new(n))
+}
+
+fn h(w: i128, x: i128, y: i128, z: i128) -> H {
+    Quaternion::new(r(w), r(x), r(y), r(z))
+}
+
+/// Solves `a * x = b` and `x * a = b` only relying on the division ring structure.
+fn solve<R: DivisionRing>(a: &R, b: &R) -> (R, R) {
+    (R::left_divide(b, a), R::divide(b, a))
+}
+
+#[test]
+fn hamilton_units() {
+    let (i, j, k) = (H::i(), H::j(), H::k());
+    assert_eq!(i * i, -H::one());
+    assert_eq!(j * j, -H::one());
+    assert_eq!(k * k, -H::one());
+    assert_eq!(i * j * k, -H::one());
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, -k);
+    assert_eq!(j * k, i);
+    assert_eq!(k * i, j);
+}
+
+#[test]
+fn norm_and_conjugate() {
+    let p = h(1, 2, 3, 4);
+    let q = h(-2, 0, 5, 1);
+    assert_eq!(p.norm(), r(30));
+    assert_eq!(p.trace(), r(2));
+    assert_eq!(p * p.conjugate(), H::from(r(30)));
+    assert_eq!((p * q).norm(), p.norm() * q.norm());
+    assert_eq!((p * q).conjugate(), q.conjugate() * p.conjugate());
+    assert_eq!(p.to_string(), "1 + 2i + 3j + 4k");
+    assert_eq!(H::j().to_string(), "j");
+}
+
+#[test]
+fn division() {
+    let p = h(1, 2, 3, 4);
+    let q = h(-2, 0, 5, 1);
+    assert_ne!(p * q, q * p);
+
+    // Right division: (p / q) * q = p, left division: q * (q \ p) = p
+    assert_eq!((p / q) * q, p);
+    let left = H::left_divide(&p, &q);
+    assert_eq!(q * left, p);
+    assert_ne!(left, p / q);
+    assert_eq!(p.multiplicative_inverse() * p, H::one());
+
+    let (x, y) = solve(&q, &p);
+    assert_eq!(q * x, p);
+    assert_eq!(y * q, p);
+    assert_eq!(H::zero().inverse(), None);
+}
+
+#[test]
+#[should_panic]
+fn division_by_zero() {
+    let _ = h(1, 2, 3, 4) / H::zero();
+}
+
+#[test]
+fn zero_divisors() {
+    type S = Quaternion<Rational, Split>;
+    let j = S::j();
+    assert_eq!(j * j, S::one());
+    assert_eq!(S::i() * S::i(), -S::one());
+    let a = S::one() + j;
+    let b = S::one() - j;
+    assert!((a * b).is_zero());
+    assert_eq!(a.norm(), r(0));
+    assert_eq!(a.inverse(), None);
+    assert!(S::i().is_unit());
+
+    // The Hamilton quaternions over a finite field split as well
+    type F = ZMod<7>;
+    let q = Quaternion::<F>::new(F::new(1), F::new(2), F::new(3), F::new(0));
+    assert!(q.norm().is_zero());
+    assert_eq!(q.inverse(), None);
+    let u = Quaternion::<F>::new(F::new(1), F::new(1), F::new(0), F::new(0));
+    assert_eq!(u * u.inverse().unwrap(), Quaternion::one());
+}
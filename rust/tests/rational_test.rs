@@ -0,0 +1,128 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+
+fn rat(n: i128, d: i128) -> Rational {
+    Rational::new(Integer::new(n), Integer::new(d))
+}
+
+#[test]
+fn normalization() {
+    let r = rat(6, -4);
+    assert_eq!(r.numer(), &Integer::new(-3));
+    assert_eq!(r.denom(), &Integer::new(2));
+    assert_eq!(rat(0, -5), Rational::zero());
+    assert_eq!(rat(10, 5), Rational::from(2));
+}
+
+#[test]
+#[should_panic]
+fn zero_denominator() {
+    rat(1, 0);
+}
+
+#[test]
+fn arithmetic() {
+    assert_eq!(rat(1, 2) + rat(1, 3), rat(5, 6));
+    assert_eq!(rat(1, 2) - rat(1, 3), rat(1, 6));
+    assert_eq!(rat(2, 3) * rat(9, 4), rat(3, 2));
+    assert_eq!(rat(2, 3) / rat(4, 9), rat(3, 2));
+    assert_eq!(-rat(2, 3), rat(-2, 3));
+    assert_eq!(rat(2, 3).inverse(), Some(rat(3, 2)));
+    assert_eq!(Rational::zero().inverse(), None);
+    assert_eq!(rat(-2, 3).pow(-2), rat(9, 4));
+}
+
+#[test]
+fn ordering() {
+    assert!(rat(1, 3) < rat(1, 2));
+    assert!(rat(-1, 2) < rat(-1, 3));
+    assert_eq!(rat(3, 4).max(rat(2, 3)), rat(3, 4));
+}
+
+#[test]
+fn rounding() {
+    assert_eq!(rat(7, 2).floor(), Integer::new(3));
+    assert_eq!(rat(-7, 2).floor(), Integer::new(-4));
+    assert_eq!(rat(7, 2).ceil(), Integer::new(4));
+    assert_eq!(rat(-7, 2).ceil(), Integer::new(-3));
+    assert_eq!(rat(7, 2).round(), Integer::new(4));
+    assert_eq!(rat(-7, 2).round(), Integer::new(-4));
+    assert_eq!(rat(5, 3).round(), Integer::new(2));
+    assert_eq!(rat(-4, 3).round(), Integer::new(-1));
+    assert_eq!(rat(-7, 2).fract(), rat(1, 2));
+}
+
+#[test]
+fn from_float() {
+    assert_eq!(Rational::try_from(0.5), Ok(rat(1, 2)));
+    assert_eq!(Rational::try_from(-3.75), Ok(rat(-15, 4)));
+    assert_eq!(Rational::try_from(1024.0), Ok(Rational::from(1024)));
+    assert_eq!(Rational::try_from(0.0), Ok(Rational::zero()));
+    assert_eq!(
+        Rational::try_from(0.1),
+        Ok(rat(3602879701896397, 36028797018963968))
+    );
+    assert_eq!(Rational::try_from(f64::NAN), Err(FromFloatErr::NotFinite));
+    assert_eq!(Rational::try_from(1e300), Err(FromFloatErr::Overflow));
+    assert_eq!(Rational::try_from(1e-300), Err(FromFloatErr::Overflow));
+    assert_eq!(f64::from(rat(-15, 4)), -3.75);
+}
+
+#[test]
+fn parse() {
+    assert_eq!("3/4".parse(), Ok(rat(3, 4)));
+    assert_eq!(" -6 / 8 ".parse(), Ok(rat(-3, 4)));
+    assert_eq!("1.25".parse(), Ok(rat(5, 4)));
+    assert_eq!("-0.5".parse(), Ok(rat(-1, 2)));
+    assert_eq!(".5".parse(), Ok(rat(1, 2)));
+    assert_eq!("42".parse(), Ok(Rational::from(42)));
+    assert_eq!("1.5e2".parse(), Ok(Rational::from(150)));
+    assert_eq!("25e-3".parse(), Ok(rat(1, 40)));
+    assert_eq!("".parse::<Rational>(), Err(ParseRationalErr::Empty));
+    assert_eq!(
+        "1/0".parse::<Rational>(),
+        Err(ParseRationalErr::ZeroDenominator)
+    );
+    assert_eq!(
+        "1.2.3".parse::<Rational>(),
+        Err(ParseRationalErr::InvalidDigit)
+    );
+    assert_eq!(
+        "abc".parse::<Rational>(),
+        Err(ParseRationalErr::InvalidDigit)
+    );
+
+    // Exponents out of range fail instead of overflowing
+    assert_eq!(
+        "1.00000e-2147483648".parse::<Rational>(),
+        Err(ParseRationalErr::Overflow)
+    );
+    assert_eq!(
+        "1e2147483647".parse::<Rational>(),
+        Err(ParseRationalErr::Overflow)
+    );
+    assert_eq!("1e-39".parse::<Rational>(), Err(ParseRationalErr::Overflow));
+    assert_eq!(
+        "1e99999999999".parse::<Rational>(),
+        Err(ParseRationalErr::Overflow)
+    );
+    assert_eq!(
+        "1e-99999999999".parse::<Rational>(),
+        Err(ParseRationalErr::Overflow)
+    );
+    assert_eq!(
+        "1e1x".parse::<Rational>(),
+        Err(ParseRationalErr::InvalidDigit)
+    );
+    assert_eq!(
+        "1e-38".parse(),
+        Ok(Rational::new(Integer::new(1), Integer::new(10i128.pow(38))))
+    );
+    assert_eq!("-0.000e-2147483648".parse(), Ok(Rational::zero()));
+}
+
+#[test]
+fn display() {
+    assert_eq!(format!("{}", rat(-3, 4)), "-3/4");
+    assert_eq!(format!("{}", rat(8, 4)), "2");
+}
@@ -0,0 +1,135 @@
+use polydiv::number_theory::*;
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+fn int(n: i128) -> Integer {
+    Integer::new(n)
+}
+
+fn rat(n: i128, d: i128) -> Rational {
+    Rational::new(int(n), int(d))
+}
+
+/// Maps `n / d` to `n * d⁻¹ (mod m)`.
+fn image(r: Rational, m: i128) -> Integer {
+    let inverse = extended_gcd(*r.denom(), int(m)).x;
+    *r.numer() * inverse % int(m)
+}
+
+#[test]
+fn half_extended() {
+    // 240 = 5 * 46 + 10, 46 = 4 * 10 + 6, 10 = 1 * 6 + 4, ...
+    for bound in [0, 3, 7, 45, 300] {
+        let (r, y) = half_extended_gcd(int(240), int(46), bound);
+        assert!(r.euclidean_value() <= bound);
+        assert_eq!((r - y * int(46)) % int(240), int(0));
+    }
+    assert_eq!(half_extended_gcd(int(240), int(46), 7), (int(6), int(21)));
+}
+
+#[test]
+fn reconstruction() {
+    let m = 1_000_000_007;
+    for r in [
+        rat(0, 1),
+        rat(1, 3),
+        rat(-22, 7),
+        rat(355, 113),
+        rat(-1, 1),
+        rat(22359, 22360),
+    ] {
+        assert_eq!(rational_reconstruction(image(r, m), int(m)), Ok(r));
+    }
+    // The bounds are ⌊√((m - 1) / 2)⌋ = 22360
+    assert_eq!(
+        rational_reconstruction(image(rat(1, 22362), m), int(m)),
+        Err(ReconstructionErr::NoSolution)
+    );
+    assert_eq!(
+        rational_reconstruction_with_bounds(image(rat(1, 22362), m), int(m), int(10), int(30000)),
+        Ok(rat(1, 22362))
+    );
+
+    // For m = 8 the bound is 1, as 2 * 2² isn't smaller than 8
+    assert_eq!(rational_reconstruction(int(7), int(8)), Ok(rat(-1, 1)));
+    assert_eq!(
+        rational_reconstruction(int(2), int(8)),
+        Err(ReconstructionErr::NoSolution)
+    );
+}
+
+#[test]
+fn reconstruction_errors() {
+    assert_eq!(
+        rational_reconstruction(int(3), int(0)),
+        Err(ReconstructionErr::InvalidModulus)
+    );
+    assert_eq!(
+        rational_reconstruction_with_bounds(int(3), int(100), int(5), int(10)),
+        Err(ReconstructionErr::InvalidBounds)
+    );
+    assert_eq!(
+        rational_reconstruction_with_bounds(int(3), int(100), int(-1), int(10)),
+        Err(ReconstructionErr::InvalidBounds)
+    );
+    assert_eq!(
+        rational_reconstruction_with_bounds(int(3), int(100), int(7), int(0)),
+        Err(ReconstructionErr::InvalidBounds)
+    );
+    // 2 * 4 * 6 < 97, but 10 isn't congruent to any n / d with |n| <= 4 and d <= 6
+    assert_eq!(
+        rational_reconstruction_with_bounds(int(10), int(97), int(4), int(6)),
+        Err(ReconstructionErr::NoSolution)
+    );
+}
+
+#[test]
+fn multi_modular() {
+    let values = [rat(123456789, 987654321), rat(-5, 1), rat(0, 1)];
+    let mut images = MultiModular::new();
+    for p in [1_000_000_007, 998244353] {
+        let residues: Vec<_> = values.iter().map(|r| image(*r, p)).collect();
+        images.add_image(&residues, int(p)).unwrap();
+    }
+    assert_eq!(images.modulus(), int(1_000_000_007 * 998244353));
+    assert_eq!(images.reconstruct(), Ok(values.to_vec()));
+
+    let mut images = MultiModular::new();
+    images.add_image(&[int(3), int(-2)], int(7)).unwrap();
+    images.add_image(&[int(1), int(-2)], int(11)).unwrap();
+    assert_eq!(images.reconstruct_integers(), vec![int(-32), int(-2)]);
+    assert_eq!(
+        images.add_image(&[int(1), int(1)], int(14)),
+        Err(ReconstructionErr::Crt(CrtErr::NotCoprime))
+    );
+    assert_eq!(
+        images.add_image(&[int(1), int(1)], int(i128::MAX / 3)),
+        Err(ReconstructionErr::ModulusOverflow)
+    );
+    assert_eq!(images.modulus(), int(77));
+}
+
+#[test]
+fn lifting() {
+    // The harmonic number H_30 = 1 + 1/2 + ... + 1/30, computed modulo small primes
+    let expected = (1..=30).fold(rat(0, 1), |acc, k| acc + rat(1, k));
+    let mut used = 0;
+    let result = lift_rational(primes_below(Natural::new(1 << 20)), |p| {
+        let ring = Zn::new(p.value()).unwrap();
+        used += 1;
+        (1..=30)
+            .map(|k| ring.element(k).inverse())
+            .try_fold(ring.zero(), |acc, inv| Some(acc + inv?))
+            .map(Integer::from)
+    });
+    assert_eq!(result, Ok(expected));
+    assert!(used < 10);
+
+    // Primes dividing a denominator are skipped
+    let result = lift_rationals([5, 7, 3, 11, 13, 17, 19].map(Natural::new), |p| {
+        let p = p.value() as i128;
+        (p != 3 && p != 5).then(|| vec![image(rat(1, 3), p), image(rat(-2, 5), p)])
+    });
+    assert_eq!(result, Ok(vec![rat(1, 3), rat(-2, 5)]));
+}
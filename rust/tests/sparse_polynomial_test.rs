@@ -0,0 +1,217 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+use polydiv::util::*;
+
+type S = SparsePolynomial<Rational>;
+type P = Polynomial<Rational>;
+type F2 = SparsePolynomial<ZMod<2>>;
+
+fn r(n: i128) -> Rational {
+    Rational::from(Integer::new(n))
+}
+
+fn s(terms: &[(usize, i128)]) -> S {
+    SparsePolynomial::from_terms(terms.iter().map(|(p, c)| (*p, r(*c))).collect())
+}
+
+fn f2(pows: &[usize]) -> F2 {
+    SparsePolynomial::from_terms(pows.iter().map(|p| (*p, ZMod::new(1))).collect())
+}
+
+#[test]
+fn construction() {
+    let a = s(&[(1000000, 1), (0, 1)]);
+    assert_eq!(a.len(), 2);
+    assert_eq!(a.degree(), Some(1000000));
+    assert_eq!(a.coef(1000000), r(1));
+    assert_eq!(a.coef(5), r(0));
+    assert_eq!(a.to_string(), "x^1000000 + 1");
+
+    assert_eq!(s(&[(3, 1), (1, 2), (3, -1)]), s(&[(1, 2)]));
+    assert_eq!(s(&[(2, 0)]), S::zero());
+    assert_eq!(S::x(), s(&[(1, 1)]));
+
+    let mut b = s(&[(1, 2)]);
+    b.set_coef(5, r(3));
+    b.set_coef(1, r(0));
+    b.set_coef(0, r(1));
+    assert_eq!(b, s(&[(0, 1), (5, 3)]));
+}
+
+#[test]
+fn dense_conversion() {
+    let dense = Polynomial::from_vec(vec![r(1), r(0), r(0), r(4)]);
+    let sparse = S::from(dense.clone());
+    assert_eq!(sparse, s(&[(0, 1), (3, 4)]));
+    assert_eq!(P::from(sparse), dense);
+    assert_eq!(P::from(S::zero()), P::zero());
+}
+
+#[test]
+fn ring_ops() {
+    let a = s(&[(0, 1), (10, 2)]);
+    let b = s(&[(5, 3), (10, -2)]);
+    assert_eq!(&a + &b, s(&[(0, 1), (5, 3)]));
+    assert_eq!(&a - &a, S::zero());
+    assert_eq!(-&a, s(&[(0, -1), (10, -2)]));
+    assert_eq!(&a * &b, s(&[(5, 3), (10, -2), (15, 6), (20, -4)]));
+
+    // The sparse arithmetic agrees with the dense one
+    let (da, db) = (P::from(a.clone()), P::from(b.clone()));
+    assert_eq!(P::from(&a * &b), &da * &db);
+    assert_eq!(P::from(&a - &b), &da - &db);
+    assert_eq!(P::from(a.derivative()), da.derivative());
+    assert_eq!(
+        s(&[(1000000, 1), (0, 1)]).derivative(),
+        s(&[(999999, 1000000)])
+    );
+    assert_eq!(f2(&[7, 6, 3]).derivative(), f2(&[6, 2]));
+    assert_eq!(a.eval(&r(2)), da.eval(&r(2)));
+    assert_eq!(s(&[(3, 1)]).eval(&r(3)), r(27));
+
+    let mut c = a.clone();
+    c.shl(3);
+    assert_eq!(c, s(&[(3, 1), (13, 2)]));
+    c.shr(5);
+    assert_eq!(c, s(&[(8, 2)]));
+}
+
+#[test]
+fn division() {
+    let a = s(&[(0, -4), (2, -2), (3, 1)]);
+    let b = s(&[(0, -3), (1, 1)]);
+    assert_eq!(a.div_rem(&b), (s(&[(0, 3), (1, 1), (2, 1)]), s(&[(0, 5)])));
+    assert_eq!(&a / &b, s(&[(0, 3), (1, 1), (2, 1)]));
+    assert_eq!(&a % &b, s(&[(0, 5)]));
+
+    let (da, db) = (P::from(a.clone()), P::from(b.clone()));
+    let (q, rem) = da.div_rem(&db);
+    assert_eq!((S::from(q), S::from(rem)), a.div_rem(&b));
+
+    assert_eq!(
+        gcd(s(&[(0, -1), (2, 1)]), s(&[(0, 1), (1, 2), (2, 1)])),
+        s(&[(0, 1), (1, 1)])
+    );
+}
+
+#[test]
+fn inexact_division() {
+    // The leading terms don't cancel exactly in floating-point arithmetic
+    let a = SparsePolynomial::from_terms(vec![(3, Float::new(1.0)), (0, Float::new(1.0))]);
+    let b = SparsePolynomial::from_terms(vec![(1, Float::new(49.0)), (0, Float::new(3.0))]);
+    let (q, rem) = a.div_rem(&b);
+    assert_eq!(q.degree(), Some(2));
+    let pows: Vec<usize> = q.terms().map(|(p, _)| p).collect();
+    assert_eq!(pows, vec![0, 1, 2]);
+    assert!(rem.degree().is_none_or(|d| d == 0));
+    let (dq, drem) = Polynomial::<Float>::from(a).div_rem(&Polynomial::from(b));
+    assert_eq!(Polynomial::<Float>::from(q), dq);
+    assert_eq!(Polynomial::<Float>::from(rem), drem);
+}
+
+#[test]
+fn trinomial_reduction() {
+    // GF(2^127) with the trinomial x^127 + x + 1
+    let modulus = f2(&[127, 1, 0]);
+    assert_eq!(f2(&[127]) % modulus.clone(), f2(&[1, 0]));
+    assert_eq!(f2(&[128]) % modulus.clone(), f2(&[2, 1]));
+
+    // x^(2^127) ≡ x, as the trinomial is irreducible
+    let mut a = f2(&[1]);
+    for _ in 0..127 {
+        a = (&a * &a) % modulus.clone();
+    }
+    assert_eq!(a, f2(&[1]));
+
+    let big = f2(&[100000, 3, 0]);
+    let (q, rem) = big.div_rem(&modulus);
+    assert_eq!(&q * &modulus + rem.clone(), big);
+    assert!(rem.degree().unwrap() < 127);
+}
+
+#[test]
+fn powers() {
+    let a = s(&[(0, 1), (7, -2)]);
+    assert_eq!(a.square(), &a * &a);
+    assert_eq!(a.pow(0), S::one());
+    assert_eq!(P::from(a.pow(5)), P::from(a.clone()).pow(5));
+    assert_eq!(S::x().pow(1 << 40).into_terms(), vec![(1 << 40, r(1))]);
+
+    // x^(2^(8k)) mod the irreducible x^8 + x^4 + x^3 + x + 1 over GF(2), i.e. the Frobenius of GF(2^8)
+    let modulus = f2(&[8, 4, 3, 1, 0]);
+    let mut a = f2(&[1]);
+    for k in 1..=8 {
+        a = a.pow(2) % modulus.clone();
+        assert_eq!(a == f2(&[1]), k == 8);
+    }
+    assert_eq!(f2(&[1]).pow(256) % modulus.clone(), f2(&[1]));
+}
+
+#[test]
+fn division_over_rings() {
+    type SI = SparsePolynomial<Integer>;
+    type PI = Polynomial<Integer>;
+    let int = |terms: &[(usize, i128)]| -> SI {
+        SparsePolynomial::from_terms(terms.iter().map(|(p, c)| (*p, Integer::new(*c))).collect())
+    };
+    let a = int(&[(12, 3), (5, -7), (1, 4), (0, 2)]);
+    let monic = int(&[(3, 1), (0, -5)]);
+    let b = int(&[(3, 2), (2, 1), (0, 3)]);
+
+    let (q, rem) = a.try_div_rem(&monic).unwrap();
+    assert_eq!(&q * &monic + rem.clone(), a);
+    assert!(rem.degree().unwrap() < 3);
+    assert_eq!(
+        a.try_div_rem(&b),
+        Err(PolynomialDivErr::NonUnitLeadingCoefficient)
+    );
+    assert_eq!(
+        a.try_div_rem(&SI::zero()),
+        Err(PolynomialDivErr::DivideByZero)
+    );
+
+    // The pseudo-division agrees with the dense one, even if steps are skipped
+    let (q, rem) = a.pseudo_div_rem(&b).unwrap();
+    let (dq, drem) = PI::from(a.clone())
+        .pseudo_div_rem(&PI::from(b.clone()))
+        .unwrap();
+    assert_eq!((PI::from(q), PI::from(rem)), (dq, drem));
+    assert_eq!(b.pseudo_div_rem(&a).unwrap(), (SI::zero(), b.clone()));
+
+    // Also over a ring with zero divisors
+    type S6 = SparsePolynomial<ZMod<6>>;
+    let z6 = |terms: &[(usize, u64)]| -> S6 {
+        SparsePolynomial::from_terms(terms.iter().map(|(p, c)| (*p, ZMod::new(*c))).collect())
+    };
+    let (a6, b6) = (z6(&[(9, 1), (4, 5), (0, 3)]), z6(&[(2, 2), (1, 3), (0, 1)]));
+    type P6 = Polynomial<ZMod<6>>;
+    let (q, rem) = a6.pseudo_div_rem(&b6).unwrap();
+    let (dq, drem) = P6::from(a6).pseudo_div_rem(&P6::from(b6)).unwrap();
+    assert_eq!((P6::from(q), P6::from(rem)), (dq, drem));
+
+    let product = &a * &b;
+    assert_eq!(product.exact_div(&b), Ok(a.clone()));
+    assert_eq!(
+        (&product + &SI::one()).exact_div(&b),
+        Err(PolynomialDivErr::NotExact)
+    );
+    assert_eq!(
+        a.exact_div(&int(&[(1, 2)])),
+        Err(PolynomialDivErr::NotExact)
+    );
+}
+
+#[test]
+fn inverse_modulo() {
+    let modulus = f2(&[8, 4, 3, 1, 0]);
+    let a = f2(&[6, 4, 1, 0]);
+    let inverse = a.inverse_mod(&modulus).unwrap();
+    assert!(inverse.degree().unwrap() < 8);
+    assert_eq!((&a * &inverse) % modulus.clone(), F2::one());
+    assert_eq!(f2(&[2, 1]).inverse_mod(&f2(&[2, 0])), None);
+
+    let m = s(&[(5, 1), (0, -2)]);
+    let b = s(&[(3, 1), (0, 1)]);
+    let dense = P::from(b.clone()).inverse_mod(&P::from(m.clone()));
+    assert_eq!(b.inverse_mod(&m).map(P::from), dense);
+}
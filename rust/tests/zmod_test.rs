@@ -0,0 +1,107 @@
+use polydiv::traits::*;
+use polydiv::types::*;
+
+type F7 = ZMod<7>;
+type Z12 = ZMod<12>;
+
+#[test]
+fn arithmetic() {
+    assert_eq!(F7::new(5) + F7::new(4), F7::new(2));
+    assert_eq!(F7::new(2) - F7::new(5), F7::new(4));
+    assert_eq!(-F7::new(3), F7::new(4));
+    assert_eq!(-F7::zero(), F7::zero());
+    assert_eq!(F7::new(3) * F7::new(5), F7::new(1));
+    assert_eq!(F7::new(30), F7::new(2));
+    assert_eq!(F7::from(Integer::new(-1)), F7::new(6));
+    assert_eq!(F7::new(3).pow(6), F7::one());
+    assert_eq!(format!("{}", F7::new(10)), "3");
+}
+
+#[test]
+fn large_modulus() {
+    type F = ZMod<18446744073709551557>;
+    let a = F::new(u64::MAX - 100);
+    assert_eq!(a + a, F::new(18446744073709551473));
+    assert_eq!(a * a.multiplicative_inverse(), F::one());
+    assert_eq!(a.pow(18446744073709551556), F::one());
+}
+
+#[test]
+fn inverses() {
+    assert_eq!(F7::new(3).inverse(), Some(F7::new(5)));
+    assert_eq!(F7::zero().inverse(), None);
+    assert_eq!(F7::new(6) / F7::new(3), F7::new(2));
+    for x in Residues::<7>.into_iter().skip(1) {
+        assert_eq!(x * x.multiplicative_inverse(), F7::one());
+    }
+
+    assert_eq!(Z12::new(5).inverse(), Some(Z12::new(5)));
+    assert_eq!(Z12::new(4).inverse(), None);
+    assert!(Z12::new(7).is_unit());
+}
+
+#[test]
+fn fields() {
+    assert!(ZMod::<2>::is_field());
+    assert!(ZMod::<998244353>::is_field());
+    assert!(!Z12::is_field());
+    assert!(!ZMod::<561>::is_field());
+    assert!(!ZMod::<3215031751>::is_field());
+
+    // The field operations are available for every prime modulus
+    fn inverse_of_two<F: Field>() -> F {
+        (F::one() + F::one()).multiplicative_inverse()
+    }
+    assert_eq!(inverse_of_two::<F7>(), F7::new(4));
+    assert_eq!(inverse_of_two::<ZMod<65537>>(), ZMod::new(32769));
+    assert_eq!(
+        inverse_of_two::<ZMod<2305843009213693951>>().value(),
+        1 << 60
+    );
+    assert_eq!(inverse_of_two::<ZMod<10007>>(), ZMod::new(5004));
+    assert_eq!(ZMod::<10009>::new(3) / ZMod::new(3), ZMod::one());
+    assert_eq!(ZMod::<10009>::new(4).sqrt(), Some(ZMod::new(2)));
+    assert_eq!(ZMod::<10007>::new(10006).legendre(), -1);
+}
+
+#[test]
+fn enumeration() {
+    let residues: Vec<_> = Residues::<5>.into_iter().map(|x| x.value()).collect();
+    assert_eq!(residues, vec![0, 1, 2, 3, 4]);
+    assert!(matches!(Set::size(&Residues::<5>), SetSize::Finite(5)));
+}
+
+fn check_against_naive<const N: u64>() {
+    let samples = [
+        0,
+        1,
+        2,
+        N / 3,
+        N / 2,
+        N - 2,
+        N - 1,
+        0x9e37_79b9_7f4a_7c15 % N,
+    ];
+    for a in samples {
+        for b in samples {
+            let expected = (a as u128 * b as u128 % N as u128) as u64;
+            assert_eq!((ZMod::<N>::new(a) * ZMod::<N>::new(b)).value(), expected);
+            let expected = ((a as u128 + b as u128) % N as u128) as u64;
+            assert_eq!((ZMod::<N>::new(a) + ZMod::<N>::new(b)).value(), expected);
+        }
+        assert_eq!(ZMod::<N>::new(a).value(), a % N);
+    }
+}
+
+#[test]
+fn reduction_backends() {
+    // Montgomery
+    check_against_naive::<3>();
+    check_against_naive::<1_000_000_007>();
+    check_against_naive::<{ u64::MAX }>();
+    // Barrett
+    check_against_naive::<2>();
+    check_against_naive::<{ 1 << 63 }>();
+    check_against_naive::<{ u64::MAX - 1 }>();
+    assert!(ZMod::<9>::new(3) < ZMod::<9>::new(4));
+}
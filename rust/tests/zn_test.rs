@@ -0,0 +1,81 @@
+use polydiv::types::*;
+
+#[test]
+fn construction() {
+    assert_eq!(Zn::new(0), Err(ZnErr::InvalidModulus));
+    assert_eq!(Zn::new(1), Err(ZnErr::InvalidModulus));
+    let ring = Zn::new(10).unwrap();
+    assert_eq!(ring.element(23).value(), 3);
+    assert_eq!(ring.from_integer(Integer::new(-3)).value(), 7);
+    assert_eq!(ring.elements().count(), 10);
+    assert!(!ring.is_field());
+    assert!(Zn::new(1_000_000_007).unwrap().is_field());
+    assert_eq!(format!("{ring}"), "Z/10Z");
+}
+
+#[test]
+fn arithmetic() {
+    let ring = Zn::new(13).unwrap();
+    let a = ring.element(9);
+    let b = ring.element(7);
+    assert_eq!((a + b).value(), 3);
+    assert_eq!((a - b).value(), 2);
+    assert_eq!((b - a).value(), 11);
+    assert_eq!((-a).value(), 4);
+    assert_eq!((a * b).value(), 11);
+    assert_eq!((a / b * b), a);
+    assert_eq!(a.pow(12), ring.one());
+}
+
+#[test]
+fn large_modulus() {
+    let n = u64::MAX - 58;
+    let ring = Zn::new(n).unwrap();
+    let a = ring.element(n - 1);
+    assert_eq!(a * a, ring.one());
+    let b = ring.element(0x1234_5678_9abc_def0);
+    let expected = (0x1234_5678_9abc_def0u128 * 0x1234_5678_9abc_def0u128 % n as u128) as u64;
+    assert_eq!((b * b).value(), expected);
+    for m in [2, 3, 1 << 32, (1 << 63) + 1, u64::MAX] {
+        let ring = Zn::new(m).unwrap();
+        let x = ring.element(u64::MAX);
+        let expected = ((u64::MAX % m) as u128 * (u64::MAX % m) as u128 % m as u128) as u64;
+        assert_eq!((x * x).value(), expected);
+    }
+}
+
+#[test]
+fn inverses() {
+    let ring = Zn::new(12).unwrap();
+    assert_eq!(ring.element(5).inverse(), Some(ring.element(5)));
+    assert_eq!(ring.element(4).inverse(), None);
+    assert_eq!(
+        ring.element(1).checked_div(&ring.element(4)),
+        Err(ZnErr::NotInvertible)
+    );
+}
+
+#[test]
+fn modulus_mismatch() {
+    let a = Zn::new(7).unwrap().element(3);
+    let b = Zn::new(11).unwrap().element(3);
+    let mismatch = Err(ZnErr::ModulusMismatch { lhs: 7, rhs: 11 });
+    assert_eq!(a.checked_add(&b), mismatch);
+    assert_eq!(a.checked_sub(&b), mismatch);
+    assert_eq!(a.checked_mul(&b), mismatch);
+    assert_eq!(a.checked_div(&b), mismatch);
+    assert_eq!(
+        a.checked_add(&b).unwrap_err().to_string(),
+        "the operands are residues modulo 7 and 11"
+    );
+    let err: Box<dyn std::error::Error> = Box::new(ZnErr::InvalidModulus);
+    assert_eq!(err.to_string(), "the modulus has to be greater than 1");
+}
+
+#[test]
+#[should_panic(expected = "Modulus mismatch error")]
+fn modulus_mismatch_operator() {
+    let a = Zn::new(7).unwrap().element(3);
+    let b = Zn::new(11).unwrap().element(3);
+    let _ = a + b;
+}